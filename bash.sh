@@ -1,5 +0,0 @@
-for i in {1..25}
-do
-   echo > inputs/day-$i.txt
-   echo > src/bin/day-$i.rs
-done
\ No newline at end of file
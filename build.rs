@@ -0,0 +1,68 @@
+// Generate one test per example input and part, see `tests/examples.rs`.
+//
+// Every `inputs/day-N-test*.txt` is an example. Its expected answers are read
+// from the sidecar file with the same name and an `.answers` extension, one
+// `part: answer` line per part, e.g. `inputs/day-8-test-3.answers`:
+//
+//     # only part 2 has this example
+//     2: 6
+//
+// An example without a sidecar file still gets a test, ignored until its
+// answers are known.
+
+use std::{env, fmt::Write, fs, path::Path};
+
+fn main() {
+    println!("cargo:rerun-if-changed=inputs");
+    let mut examples = fs::read_dir("inputs")
+        .expect("ERROR: cannot read inputs/")
+        .map(|entry| entry.expect("ERROR: cannot read inputs/").path())
+        .filter_map(|path| {
+            let name = path.file_name()?.to_str()?;
+            let day = name.strip_prefix("day-")?.split_once("-test")?.0;
+            let day = day.parse::<u8>().ok()?;
+            let stem = name.strip_suffix(".txt")?.to_string();
+            Some((day, stem))
+        })
+        .collect::<Vec<_>>();
+    examples.sort();
+
+    let mut tests = String::new();
+    for (day, stem) in examples {
+        let test_name = stem.replace('-', "_");
+        let sidecar = Path::new("inputs").join(format!("{stem}.answers"));
+        let Ok(answers) = fs::read_to_string(&sidecar) else {
+            writeln!(
+                tests,
+                "#[test]\n#[ignore = \"no {stem}.answers yet\"]\nfn {test_name}() {{}}\n"
+            )
+            .unwrap();
+            continue;
+        };
+        for (i, line) in answers.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (part, answer) = match line.split_once(':') {
+                Some((part @ ("1" | "2"), answer)) if !answer.trim().is_empty() => {
+                    (part, answer.trim())
+                }
+                _ => panic!(
+                    "ERROR: {} line {}: expected `1: <answer>` or `2: <answer>`",
+                    sidecar.display(),
+                    i + 1
+                ),
+            };
+            writeln!(
+                tests,
+                "#[test]\nfn {test_name}_part_{part}() {{\n    check({day}, {part}, {:?}, {answer:?});\n}}\n",
+                format!("{stem}.txt")
+            )
+            .unwrap();
+        }
+    }
+
+    let out = Path::new(&env::var("OUT_DIR").unwrap()).join("examples.rs");
+    fs::write(out, tests).expect("ERROR: cannot write the example tests");
+}
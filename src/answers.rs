@@ -0,0 +1,245 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt::Display,
+    fs,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use crate::{
+    runner::{self, Status},
+    solution::Registry,
+    Error, Result,
+};
+
+pub const HEADER: &str = "day,part,input,answer";
+
+/// Known answers, keyed by day, part and input file name (relative to
+/// `inputs/`). Stored as CSV with a `day,part,input,answer` header; blank
+/// lines and lines starting with `#` are ignored.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Manifest {
+    answers: BTreeMap<(u8, u8, String), String>,
+}
+
+pub fn default_path() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("inputs")
+        .join("answers.csv")
+}
+
+impl Manifest {
+    pub fn load(path: &Path) -> Result<Self> {
+        fs::read_to_string(path)
+            .map_err(|e| Error::io("read answers from", path, e))?
+            .parse()
+    }
+
+    pub fn get(&self, day: u8, part: u8, input: &str) -> Option<&str> {
+        self.answers
+            .get(&(day, part, input.to_string()))
+            .map(String::as_str)
+    }
+
+    pub fn insert(&mut self, day: u8, part: u8, input: &str, answer: &str) {
+        self.answers
+            .insert((day, part, input.to_string()), answer.to_string());
+    }
+
+    // Every input with at least one known answer for the day.
+    pub fn inputs(&self, day: u8) -> BTreeSet<&str> {
+        self.answers
+            .keys()
+            .filter(|(d, _, _)| *d == day)
+            .map(|(_, _, input)| input.as_str())
+            .collect()
+    }
+}
+
+impl FromStr for Manifest {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut manifest = Manifest::default();
+        let mut lines = s
+            .lines()
+            .filter(|line| !line.trim().is_empty() && !line.starts_with('#'));
+        match lines.next() {
+            Some(header) if header.trim() == HEADER => {}
+            header => {
+                let at = header.unwrap_or(s);
+                return Err(Error::at(s, at, format!("expected the header {HEADER}")));
+            }
+        }
+        for line in lines {
+            let fields = line.splitn(4, ',').map(str::trim).collect::<Vec<_>>();
+            let [day, part, input, answer] = fields[..] else {
+                return Err(Error::at(s, line, format!("expected {HEADER}")));
+            };
+            let (Ok(day), Ok(part @ 1..=2)) = (day.parse::<u8>(), part.parse::<u8>()) else {
+                return Err(Error::at(s, line, "bad day or part"));
+            };
+            manifest.insert(day, part, input, answer);
+        }
+        Ok(manifest)
+    }
+}
+
+impl Display for Manifest {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{HEADER}")?;
+        for ((day, part, input), answer) in &self.answers {
+            writeln!(f, "{day},{part},{input},{answer}")?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail,
+    // nothing is known about this part yet
+    Missing,
+    Error(String),
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Pass => write!(f, "pass"),
+            Verdict::Fail => write!(f, "FAIL"),
+            Verdict::Missing => write!(f, "missing"),
+            Verdict::Error(e) => write!(f, "ERROR: {e}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Check {
+    pub day: u8,
+    pub part: u8,
+    pub input: String,
+    pub expected: Option<String>,
+    pub actual: Option<String>,
+    pub verdict: Verdict,
+}
+
+// Run every registered day on its default input and on every other input the
+// manifest knows about. Parts without a known answer are only run on the
+// default input, where they are reported as missing.
+pub fn check(registry: &Registry, manifest: &Manifest, inputs_dir: &Path) -> Vec<Check> {
+    let mut checks = Vec::new();
+    for entry in registry.entries() {
+        let default_input = format!("day-{}.txt", entry.day);
+        let mut inputs = manifest.inputs(entry.day);
+        inputs.insert(&default_input);
+
+        for input in inputs {
+            let expected = [1, 2].map(|part| manifest.get(entry.day, part, input));
+            let parts =
+                [1, 2].map(|part| input == default_input || expected[part as usize - 1].is_some());
+            let run = match fs::read_to_string(inputs_dir.join(input)) {
+                Ok(raw) => runner::run_parts(entry, &raw, parts),
+                Err(e) => {
+                    for (part, _) in (1..=2).zip(parts).filter(|(_, run)| *run) {
+                        checks.push(Check {
+                            day: entry.day,
+                            part,
+                            input: input.to_string(),
+                            expected: expected[part as usize - 1].map(String::from),
+                            actual: None,
+                            verdict: Verdict::Error(e.to_string()),
+                        });
+                    }
+                    continue;
+                }
+            };
+
+            for (part, expected) in run.parts.iter().zip(expected) {
+                let actual = part.answer.as_ref().map(ToString::to_string);
+                let verdict = match (&part.status, expected, &actual) {
+                    (Status::Skipped, _, _) => continue,
+                    (Status::Ok, None, _) => Verdict::Missing,
+                    (Status::Ok, Some(expected), Some(actual)) if expected == actual => {
+                        Verdict::Pass
+                    }
+                    (Status::Ok, _, _) => Verdict::Fail,
+                    (status, _, _) => {
+                        Verdict::Error(status.message().unwrap_or_default().to_string())
+                    }
+                };
+                checks.push(Check {
+                    day: entry.day,
+                    part: part.part,
+                    input: input.to_string(),
+                    expected: expected.map(String::from),
+                    actual,
+                    verdict,
+                });
+            }
+        }
+    }
+    checks
+}
+
+pub fn table(checks: &[Check]) -> String {
+    let mut out = format!(
+        "{:>3}  {:>4}  {:<22} {:>20} {:>20}  result\n",
+        "day", "part", "input", "expected", "actual"
+    );
+    for check in checks {
+        out.push_str(&format!(
+            "{:>3}  {:>4}  {:<22} {:>20} {:>20}  {}\n",
+            check.day,
+            check.part,
+            check.input,
+            check.expected.as_deref().unwrap_or("-"),
+            check.actual.as_deref().unwrap_or("-"),
+            check.verdict
+        ));
+    }
+    let count =
+        |verdict: fn(&Verdict) -> bool| checks.iter().filter(|c| verdict(&c.verdict)).count();
+    out.push_str(&format!(
+        "\n{} passed, {} failed, {} missing, {} errors\n",
+        count(|v| *v == Verdict::Pass),
+        count(|v| *v == Verdict::Fail),
+        count(|v| *v == Verdict::Missing),
+        count(|v| matches!(v, Verdict::Error(_))),
+    ));
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_manifest() {
+        let manifest: Manifest = "# known answers\nday,part,input,answer\n\n1,1,day-1.txt,54632\n1,2,day-1-test-2.txt,281\n25,2,day-25.txt,🎄\n"
+            .parse()
+            .unwrap();
+        assert_eq!(manifest.get(1, 1, "day-1.txt"), Some("54632"));
+        assert_eq!(manifest.get(1, 2, "day-1.txt"), None);
+        assert_eq!(manifest.get(25, 2, "day-25.txt"), Some("🎄"));
+        assert_eq!(
+            manifest.inputs(1).into_iter().collect::<Vec<_>>(),
+            vec!["day-1-test-2.txt", "day-1.txt"]
+        );
+        assert_eq!(manifest.to_string().parse::<Manifest>().unwrap(), manifest);
+    }
+
+    #[test]
+    fn test_bad_manifest() {
+        assert!("1,1,day-1.txt,54632".parse::<Manifest>().is_err());
+        assert!("day,part,input,answer\n1,3,day-1.txt,54632"
+            .parse::<Manifest>()
+            .is_err());
+    }
+
+    #[test]
+    fn test_answers_file() {
+        Manifest::load(&default_path()).unwrap();
+    }
+}
@@ -0,0 +1,125 @@
+use std::{
+    fmt::Display,
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use crate::{solution::Entry, Result};
+
+#[derive(Debug, Clone, Copy)]
+pub struct Config {
+    // iterations run and thrown away before measuring
+    pub warmup: usize,
+    pub samples: usize,
+    // slow days stop sampling once this is spent, after at least `min_samples`
+    pub budget: Duration,
+    pub min_samples: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            warmup: 3,
+            samples: 100,
+            budget: Duration::from_secs(5),
+            min_samples: 5,
+        }
+    }
+}
+
+/// Summary of the samples of one step, in nanoseconds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub samples: usize,
+    pub min: u128,
+    pub median: u128,
+    pub p95: u128,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        let mut nanos = samples.iter().map(Duration::as_nanos).collect::<Vec<_>>();
+        nanos.sort_unstable();
+        let percentile = |p: usize| nanos[((nanos.len() - 1) * p + 50) / 100];
+        Self {
+            samples: nanos.len(),
+            min: nanos[0],
+            median: percentile(50),
+            p95: percentile(95),
+        }
+    }
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "min {:>13} ns  median {:>13} ns  p95 {:>13} ns  ({} samples)",
+            self.min, self.median, self.p95, self.samples
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Bench {
+    pub day: u8,
+    pub parse: Stats,
+    pub part_1: Stats,
+    pub part_2: Stats,
+}
+
+impl Display for Bench {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "day-{}:", self.day)?;
+        writeln!(f, "  parse   {}", self.parse)?;
+        writeln!(f, "  part 1  {}", self.part_1)?;
+        write!(f, "  part 2  {}", self.part_2)
+    }
+}
+
+fn measure<T>(config: &Config, mut step: impl FnMut() -> Result<T>) -> Result<Stats> {
+    for _ in 0..config.warmup {
+        black_box(step()?);
+    }
+    let start = Instant::now();
+    let mut samples = Vec::with_capacity(config.samples);
+    while samples.len() < config.samples.max(1) {
+        let now = Instant::now();
+        black_box(step()?);
+        samples.push(now.elapsed());
+        if samples.len() >= config.min_samples && start.elapsed() > config.budget {
+            break;
+        }
+    }
+    Ok(Stats::from_samples(&samples))
+}
+
+// Parsing is measured on its own, both parts then reuse a single parsed input.
+pub fn bench(entry: &Entry, input: &str, config: &Config) -> Result<Bench> {
+    let parse = measure(config, || (entry.parse)(input))?;
+    let parsed = (entry.parse)(input)?;
+    Ok(Bench {
+        day: entry.day,
+        parse,
+        part_1: measure(config, || parsed.part_1())?,
+        part_2: measure(config, || parsed.part_2())?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats() {
+        let samples = (1..=100)
+            .rev()
+            .map(Duration::from_nanos)
+            .collect::<Vec<_>>();
+        let stats = Stats::from_samples(&samples);
+        assert_eq!(stats.samples, 100);
+        assert_eq!(stats.min, 1);
+        assert_eq!(stats.median, 51);
+        assert_eq!(stats.p95, 95);
+    }
+}
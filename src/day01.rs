@@ -0,0 +1,120 @@
+//! Day 1: Trebuchet?!
+
+use crate::{solution::Solution, Error, Result};
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+    type Input = String;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<String> {
+        Ok(input.to_string())
+    }
+
+    fn part_1(input: &String) -> Result<u32> {
+        part_1(input)
+    }
+
+    fn part_2(input: &String) -> Result<u32> {
+        part_2(input)
+    }
+}
+
+fn part_1(input: &str) -> Result<u32> {
+    input
+        .lines()
+        .map(|line| {
+            let mut digits = line.chars().filter_map(|c| c.to_digit(10));
+            let first = digits
+                .next()
+                .ok_or_else(|| Error::at(input, line, "expected a digit"))?;
+            Ok(calculate_number(first, digits.next_back().unwrap_or(first)))
+        })
+        .sum()
+}
+
+fn part_2(input: &str) -> Result<u32> {
+    let patterns = vec![
+        "1", "2", "3", "4", "5", "6", "7", "8", "9", "one", "two", "three", "four", "five", "six",
+        "seven", "eight", "nine",
+    ];
+    let searcher = aho_corasick::AhoCorasick::builder()
+        .build(patterns.clone())
+        .map_err(|e| Error::Assumption(e.to_string()))?;
+
+    input
+        .lines()
+        .map(|line| {
+            let results = searcher
+                .find_overlapping_iter(line)
+                .map(|mat| mat.pattern())
+                .collect::<Vec<_>>();
+            match (results.first(), results.last()) {
+                (Some(first), Some(last)) => Ok(calculate_number(
+                    get_number(patterns[first.as_usize()]),
+                    get_number(patterns[last.as_usize()]),
+                )),
+                _ => Err(Error::at(
+                    input,
+                    line,
+                    "expected a digit or a spelled out digit",
+                )),
+            }
+        })
+        .sum()
+}
+
+fn calculate_number(first_digit: u32, second_digit: u32) -> u32 {
+    first_digit * 10 + second_digit
+}
+
+fn get_number(number: &str) -> u32 {
+    match number {
+        "one" | "1" => 1,
+        "two" | "2" => 2,
+        "three" | "3" => 3,
+        "four" | "4" => 4,
+        "five" | "5" => 5,
+        "six" | "6" => 6,
+        "seven" | "7" => 7,
+        "eight" | "8" => 8,
+        "nine" | "9" => 9,
+        // only called with the `patterns` of `part_2`
+        _ => unreachable!("ERROR: {number} is not a digit"),
+    }
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input() {
+        assert_eq!(
+            Day01::solve_part_1(include_str!("../inputs/day-1-test-1.txt")).unwrap(),
+            142
+        );
+        assert_eq!(
+            Day01::solve_part_2(include_str!("../inputs/day-1-test-2.txt")).unwrap(),
+            281
+        );
+    }
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(
+            Day01::solve_part_1(include_str!("../inputs/day-1.txt")).unwrap(),
+            54_632
+        );
+    }
+
+    #[test]
+    fn test_part_2() {
+        assert_eq!(
+            Day01::solve_part_2(include_str!("../inputs/day-1.txt")).unwrap(),
+            54_019
+        );
+    }
+}
@@ -0,0 +1,132 @@
+//! Day 2: Cube Conundrum
+
+use crate::{error, solution::Solution, Error, Result};
+
+use std::str::FromStr;
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+    type Input = Vec<Game>;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> Result<Vec<Game>> {
+        input
+            .lines()
+            .map(|line| line.parse::<Game>().map_err(|e| e.within(input, line)))
+            .collect()
+    }
+
+    fn part_1(games: &Vec<Game>) -> Result<i32> {
+        Ok(games
+            .iter()
+            .filter(|g| {
+                g.cubes.iter().all(|(cube, v)| match *cube {
+                    Cube::Red => *v <= 12,
+                    Cube::Green => *v <= 13,
+                    Cube::Blue => *v <= 14,
+                })
+            })
+            .map(|g| g.id)
+            .sum::<i32>())
+    }
+
+    fn part_2(games: &Vec<Game>) -> Result<i32> {
+        Ok(games
+            .iter()
+            .map(|game| {
+                game.cubes
+                    .iter()
+                    .fold(
+                        vec![i32::MIN, i32::MIN, i32::MIN],
+                        |mut acc, (cube, value)| {
+                            match *cube {
+                                Cube::Red => acc[0] = std::cmp::max(acc[0], *value),
+                                Cube::Green => acc[1] = std::cmp::max(acc[1], *value),
+                                Cube::Blue => acc[2] = std::cmp::max(acc[2], *value),
+                            }
+                            acc
+                        },
+                    )
+                    .iter()
+                    .product::<i32>()
+            })
+            .sum::<i32>())
+    }
+}
+
+/// One game: its id and every handful of cubes revealed.
+#[derive(Debug)]
+pub struct Game {
+    id: i32,
+    cubes: Vec<(Cube, i32)>,
+}
+
+impl FromStr for Game {
+    type Err = Error;
+
+    fn from_str(line: &str) -> Result<Self> {
+        let (id, left) = error::split_once(line, line, ": ")?;
+        let id = error::parse::<i32>(line, error::split_once(line, id, " ")?.1)?;
+        Ok(Game {
+            id,
+            cubes: left
+                .split("; ")
+                .flat_map(|group| {
+                    group.split(", ").map(|pair| {
+                        let (value, t) = error::split_once(line, pair, " ")?;
+                        let cube = match t {
+                            "red" => Cube::Red,
+                            "blue" => Cube::Blue,
+                            "green" => Cube::Green,
+                            _ => return Err(error::unexpected(line, t)),
+                        };
+                        Ok((cube, error::parse::<i32>(line, value)?))
+                    })
+                })
+                .collect::<Result<Vec<(Cube, i32)>>>()?,
+        })
+    }
+}
+
+#[derive(Debug)]
+enum Cube {
+    Red,
+    Green,
+    Blue,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input() {
+        assert_eq!(
+            Day02::solve_part_1(include_str!("../inputs/day-2-test.txt")).unwrap(),
+            8
+        );
+        assert_eq!(
+            Day02::solve_part_2(include_str!("../inputs/day-2-test.txt")).unwrap(),
+            2_286
+        );
+    }
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(
+            Day02::solve_part_1(include_str!("../inputs/day-2.txt")).unwrap(),
+            3_059
+        );
+    }
+
+    #[test]
+    fn test_part_2() {
+        assert_eq!(
+            Day02::solve_part_2(include_str!("../inputs/day-2.txt")).unwrap(),
+            65_371
+        );
+    }
+}
@@ -0,0 +1,112 @@
+//! Day 3: Gear Ratios
+
+use crate::{
+    solution::Solution,
+    utils::{Grid, Pos},
+    Error, Result,
+};
+
+use std::{collections::HashMap, str::FromStr};
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+    type Input = Scheme;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Scheme> {
+        input.parse()
+    }
+
+    fn part_1(scheme: &Scheme) -> Result<u32> {
+        Ok(scheme.symbols.values().flatten().sum::<u32>())
+    }
+
+    fn part_2(scheme: &Scheme) -> Result<u32> {
+        Ok(scheme
+            .symbols
+            .iter()
+            .filter_map(|(&(_, _, symbol), v)| {
+                if symbol == '*' && v.len() == 2 {
+                    Some(v.iter().product::<u32>())
+                } else {
+                    None
+                }
+            })
+            .sum::<u32>())
+    }
+}
+
+/// The part numbers of the engine, grouped by the symbol they touch.
+#[derive(Debug)]
+pub struct Scheme {
+    //  map contains all symbols position, type and their neighbours
+    symbols: HashMap<(usize, usize, char), Vec<u32>>,
+}
+
+impl FromStr for Scheme {
+    type Err = Error;
+
+    fn from_str(input: &str) -> Result<Self> {
+        let grid = Grid::parse(input, Some)?;
+        // 1. check if the character is symbols
+        // 2. if the neighbor is a number, put them in the vec
+        let mut symbols = HashMap::new();
+        for (y, row) in grid.rows().enumerate() {
+            let mut x = 0;
+            while x < row.len() {
+                let (start, mut symbol) = (x, None);
+                while x < row.len() && row[x].is_ascii_digit() {
+                    symbol = symbol.or_else(|| find_symbol(&grid, (y, x)));
+                    x += 1;
+                }
+                if let Some(symbol) = symbol {
+                    // calculate number
+                    // the loop above only took ASCII digits
+                    let number = row[start..x]
+                        .iter()
+                        .fold(0, |acc, &c| acc * 10 + (c as u32 - '0' as u32));
+                    symbols.entry(symbol).or_insert(Vec::new()).push(number);
+                }
+                x += 1;
+            }
+        }
+        Ok(Scheme { symbols })
+    }
+}
+
+fn find_symbol(grid: &Grid<char>, pos: Pos) -> Option<(usize, usize, char)> {
+    grid.neighbours_8(pos).find_map(|(row, col)| {
+        let s = grid[(row, col)];
+        (s != '.' && !s.is_ascii_digit()).then_some((row, col, s))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    const TEST_INPUT: &str = include_str!("../inputs/day-3-test.txt");
+    #[test]
+    fn test_input() {
+        assert_eq!(Day03::solve_part_1(TEST_INPUT).unwrap(), 4_361);
+        assert_eq!(Day03::solve_part_2(TEST_INPUT).unwrap(), 467_835);
+    }
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(
+            Day03::solve_part_1(include_str!("../inputs/day-3.txt")).unwrap(),
+            527_369
+        );
+    }
+
+    #[test]
+    fn test_part_2() {
+        assert_eq!(
+            Day03::solve_part_2(include_str!("../inputs/day-3.txt")).unwrap(),
+            73_074_886
+        );
+    }
+}
@@ -0,0 +1,127 @@
+//! Day 4: Scratchcards
+
+use crate::{error, solution::Solution, Error, Result};
+
+use std::{collections::HashSet, str::FromStr};
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+    type Input = Vec<Game>;
+    type Part1 = usize;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> Result<Vec<Game>> {
+        input
+            .lines()
+            .map(|game| game.parse::<Game>().map_err(|e| e.within(input, game)))
+            .collect()
+    }
+
+    fn part_1(games: &Vec<Game>) -> Result<usize> {
+        Ok(games
+            .iter()
+            .map(|game| {
+                let count = game.winning_cards.intersection(&game.your_cards).count();
+                if count == 0 {
+                    0
+                } else {
+                    2_usize.pow((count as u32) - 1)
+                }
+            })
+            .sum::<usize>())
+    }
+
+    fn part_2(games: &Vec<Game>) -> Result<i32> {
+        let ranges = games
+            .iter()
+            .map(|game| {
+                let count = game.winning_cards.intersection(&game.your_cards).count() as i32;
+                if count == 0 {
+                    (game.id, None)
+                } else {
+                    (
+                        game.id,
+                        Some((1..=count).map(|x| x + game.id).collect::<HashSet<i32>>()),
+                    )
+                }
+            })
+            .collect::<Vec<(i32, Option<HashSet<i32>>)>>();
+
+        Ok(ranges
+            .iter()
+            .fold(vec![1; ranges.len()], |mut acc: Vec<i32>, (game_id, _)| {
+                if *game_id != 1 {
+                    for other_id in 1..*game_id {
+                        let (_, range) = &ranges[(other_id - 1) as usize];
+                        if let Some(range) = range {
+                            if range.contains(game_id) {
+                                acc[(*game_id - 1) as usize] += acc[(other_id - 1) as usize]
+                            }
+                        }
+                    }
+                }
+
+                acc
+            })
+            .iter()
+            .sum::<i32>())
+    }
+}
+
+/// One scratchcard: the winning numbers and the numbers you have.
+#[derive(Debug)]
+pub struct Game {
+    id: i32,
+    winning_cards: HashSet<i32>,
+    your_cards: HashSet<i32>,
+}
+
+impl FromStr for Game {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (winning_cards, your_cards) = error::split_once(s, s, " | ")?;
+        let (id, winning_cards) = error::split_once(s, winning_cards, ": ")?;
+
+        Ok(Self {
+            id: error::parse(s, id.split_ascii_whitespace().last().unwrap_or(id))?,
+            winning_cards: winning_cards
+                .split_ascii_whitespace()
+                .map(|card| error::parse::<i32>(s, card))
+                .collect::<Result<_>>()?,
+            your_cards: your_cards
+                .split_ascii_whitespace()
+                .map(|card| error::parse::<i32>(s, card))
+                .collect::<Result<_>>()?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    const TEST_INPUT: &str = include_str!("../inputs/day-4-test.txt");
+    #[test]
+    fn test_input() {
+        assert_eq!(Day04::solve_part_1(TEST_INPUT).unwrap(), 13);
+        assert_eq!(Day04::solve_part_2(TEST_INPUT).unwrap(), 30);
+    }
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(
+            Day04::solve_part_1(include_str!("../inputs/day-4.txt")).unwrap(),
+            21_959
+        );
+    }
+
+    #[test]
+    fn test_part_2() {
+        assert_eq!(
+            Day04::solve_part_2(include_str!("../inputs/day-4.txt")).unwrap(),
+            5_132_675
+        );
+    }
+}
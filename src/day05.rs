@@ -0,0 +1,156 @@
+//! Day 5: If You Give A Seed A Fertilizer
+
+use crate::{
+    err, error, input,
+    solution::Solution,
+    utils::{Interval, IntervalSet, RangeMap},
+    Error, Result,
+};
+
+use std::str::FromStr;
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+    type Input = Almanac;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Almanac> {
+        input.parse()
+    }
+
+    fn part_1(almanac: &Almanac) -> Result<usize> {
+        let seed_to_location = almanac.seed_to_location()?;
+        almanac
+            .seeds
+            .iter()
+            .map(|&seed| seed_to_location.get(seed) as usize)
+            .min()
+            .ok_or_else(|| Error::Unsolvable(String::from("there are no seeds")))
+    }
+
+    fn part_2(almanac: &Almanac) -> Result<usize> {
+        if !almanac.seeds.len().is_multiple_of(2) {
+            return err!(Assumption, "seeds should come in pairs of start and length");
+        }
+        let seeds = almanac
+            .seeds
+            .chunks(2)
+            .map(|w| Interval::with_len(w[0], w[1]))
+            .collect::<IntervalSet>();
+        almanac
+            .seed_to_location()?
+            .map_set(&seeds)
+            .min()
+            .map(|location| location as usize)
+            .ok_or_else(|| Error::Unsolvable(String::from("there are no seeds")))
+    }
+}
+
+/// The seeds and the chain of maps from seed to location.
+#[derive(Debug, Clone)]
+pub struct Almanac {
+    seeds: Vec<i64>,
+    // source and destination categories, and the map between them
+    maps: Vec<(String, String, RangeMap)>,
+}
+
+impl Almanac {
+    // Every map from seed to location, composed into one.
+    fn seed_to_location(&self) -> Result<RangeMap> {
+        let mut category = "seed";
+        let mut composed = RangeMap::new();
+        // each map at most once, in case they loop
+        for _ in 0..self.maps.len() {
+            if category == "location" {
+                break;
+            }
+            if let Some((_, destination, map)) =
+                self.maps.iter().find(|(source, _, _)| source == category)
+            {
+                composed = composed.then(map);
+                category = destination;
+            }
+        }
+        if category != "location" {
+            return err!(Assumption, "the maps should lead to a location");
+        }
+        Ok(composed)
+    }
+}
+
+impl FromStr for Almanac {
+    type Err = Error;
+
+    fn from_str(input: &str) -> Result<Self> {
+        let mut data = input::blocks(input);
+        let seeds = data
+            .next()
+            .unwrap_or_default()
+            .split_ascii_whitespace()
+            .skip(1)
+            .map(|seed| error::parse::<i64>(input, seed))
+            .collect::<Result<Vec<i64>>>()?;
+        let maps = data
+            .map(|map| {
+                let (content, remain) = error::split_once(input, map, "\n")?;
+                let name = content.split_ascii_whitespace().next().unwrap_or(content);
+                let (source, destination) = error::split_once(input, name, "-to-")?;
+                Ok((
+                    String::from(source),
+                    String::from(destination),
+                    parse_map(remain).map_err(|e| e.within(input, remain))?,
+                ))
+            })
+            .collect::<Result<Vec<(String, String, RangeMap)>>>()?;
+
+        Ok(Almanac { seeds, maps })
+    }
+}
+
+// One `destination source length` rule per line.
+fn parse_map(s: &str) -> Result<RangeMap> {
+    let mut map = RangeMap::new();
+    for line in s.lines() {
+        let out = line
+            .split_ascii_whitespace()
+            .map(|number| error::parse::<i64>(s, number))
+            .collect::<Result<Vec<i64>>>()?;
+        match out[..] {
+            [destination, source, range] => {
+                map.insert(Interval::with_len(source, range), destination - source)
+            }
+            _ => return Err(Error::at(s, line, "expected 3 numbers")),
+        }
+    }
+    Ok(map)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    const TEST_INPUT: &str = include_str!("../inputs/day-5-test.txt");
+    #[test]
+    fn test_input() {
+        assert_eq!(Day05::solve_part_1(TEST_INPUT).unwrap(), 35);
+        assert_eq!(Day05::solve_part_2(TEST_INPUT).unwrap(), 46);
+    }
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(
+            Day05::solve_part_1(include_str!("../inputs/day-5.txt")).unwrap(),
+            107_430_936
+        );
+    }
+
+    #[test]
+    fn test_part_2() {
+        assert_eq!(
+            Day05::solve_part_2(include_str!("../inputs/day-5.txt")).unwrap(),
+            23_738_616
+        );
+    }
+}
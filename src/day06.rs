@@ -0,0 +1,122 @@
+//! Day 6: Wait For It
+
+use crate::{error, solution::Solution, utils::isqrt, Error, Result};
+use itertools::Itertools;
+
+use std::str::FromStr;
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+    type Input = Records;
+    type Part1 = u32;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Records> {
+        input.parse()
+    }
+
+    fn part_1(records: &Records) -> Result<u32> {
+        Ok(records
+            .times
+            .iter()
+            .zip(records.distances.iter())
+            .map(|(time, distance)| {
+                let mut counter = 0;
+                for t in 0..=*time {
+                    if t * (*time - t) > *distance {
+                        counter += 1;
+                    }
+                }
+                counter
+            })
+            .product::<u32>())
+    }
+
+    fn part_2(records: &Records) -> Result<u64> {
+        // the kerning is wrong, all numbers of a line make up a single one
+        let join = |numbers: &[u32]| {
+            numbers.iter().join("").parse::<u64>().map_err(|_| {
+                Error::Assumption(String::from("the joined numbers should fit in a u64"))
+            })
+        };
+        let (time, distance) = (join(&records.times)?, join(&records.distances)?);
+
+        Ok(ways_to_win(time.into(), distance.into()))
+    }
+}
+
+// How many hold times t beat the record, t * (time - t) > distance, between
+// the roots of t^2 - time * t + distance found with an exact square root.
+fn ways_to_win(time: i128, distance: i128) -> u64 {
+    let beats = |t: i128| t * (time - t) > distance;
+    let Some(root) = isqrt(time * time - 4 * distance) else {
+        return 0;
+    };
+    // the first winning time, the rounded root is at most one off
+    let mut first = (time - root) / 2;
+    while first > 0 && beats(first - 1) {
+        first -= 1;
+    }
+    while first <= time / 2 && !beats(first) {
+        first += 1;
+    }
+    // the winning times are symmetric around time / 2
+    let last = time - first;
+    (last - first + 1).max(0) as u64
+}
+
+/// The time and record distance of every race.
+#[derive(Debug)]
+pub struct Records {
+    times: Vec<u32>,
+    distances: Vec<u32>,
+}
+
+impl FromStr for Records {
+    type Err = Error;
+
+    fn from_str(input: &str) -> Result<Self> {
+        let (time, distances) = error::split_once(input, input, "\n")?;
+        Ok(Self {
+            times: time
+                .split_ascii_whitespace()
+                .skip(1)
+                .map(|t| error::parse(input, t))
+                .collect::<Result<_>>()?,
+            distances: distances
+                .split_ascii_whitespace()
+                .skip(1)
+                .map(|t| error::parse(input, t))
+                .collect::<Result<_>>()?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    const TEST_INPUT: &str = include_str!("../inputs/day-6-test.txt");
+    #[test]
+    fn test_input() {
+        assert_eq!(Day06::solve_part_1(TEST_INPUT).unwrap(), 288);
+        assert_eq!(Day06::solve_part_2(TEST_INPUT).unwrap(), 71_503);
+    }
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(
+            Day06::solve_part_1(include_str!("../inputs/day-6.txt")).unwrap(),
+            781_200
+        );
+    }
+
+    #[test]
+    fn test_part_2() {
+        assert_eq!(
+            Day06::solve_part_2(include_str!("../inputs/day-6.txt")).unwrap(),
+            49_240_091
+        );
+    }
+}
@@ -0,0 +1,275 @@
+//! Day 7: Camel Cards
+
+use crate::{error, solution::Solution, Error, Result};
+use itertools::Itertools;
+
+use std::{cmp::Ordering, collections::HashMap, str::FromStr};
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+    type Input = Deck;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Deck> {
+        input.parse()
+    }
+
+    fn part_1(deck: &Deck) -> Result<usize> {
+        Ok(deck.winnings(false))
+    }
+
+    fn part_2(deck: &Deck) -> Result<usize> {
+        Ok(deck.winnings(true))
+    }
+}
+
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
+enum HandType {
+    FiveOfKind,
+    FourOfKind,
+    FullHouse,
+    ThreeOfKind,
+    TwoPair,
+    OnePair,
+    HighCard,
+}
+/// Every hand with its bid.
+#[derive(Debug)]
+pub struct Deck {
+    hands: Vec<Hand>,
+}
+
+impl Deck {
+    /// Total winnings, with `J` as jokers instead of jacks if `joker_mode_on`.
+    pub fn winnings(&self, joker_mode_on: bool) -> usize {
+        let mut hands = self.hands.clone();
+        if joker_mode_on {
+            for hand in hands.iter_mut() {
+                hand.joker_mode = joker_mode_on;
+                hand.change_hand_type_by_joker();
+            }
+        }
+        hands.sort_unstable();
+
+        hands
+            .iter()
+            .enumerate()
+            .fold(0, |mut acc: usize, (index, hand)| {
+                acc += hand.bet as usize * (index + 1);
+                acc
+            })
+    }
+}
+
+impl FromStr for Deck {
+    type Err = Error;
+
+    fn from_str(input: &str) -> Result<Self> {
+        Ok(Self {
+            hands: input
+                .lines()
+                .map(|line| line.parse::<Hand>().map_err(|e| e.within(input, line)))
+                .collect::<Result<Vec<Hand>>>()?,
+        })
+    }
+}
+
+/// Five cards and a bid, ordered by strength.
+#[derive(Debug, Clone)]
+pub struct Hand {
+    cards: Vec<char>,
+    bet: u32,
+    hand_type: HandType,
+    joker_mode: bool,
+}
+
+impl Hand {
+    fn get_point_by_hand_type(&self) -> u32 {
+        match self.hand_type {
+            HandType::FiveOfKind => 7,
+            HandType::FourOfKind => 6,
+            HandType::FullHouse => 5,
+            HandType::ThreeOfKind => 4,
+            HandType::TwoPair => 3,
+            HandType::OnePair => 2,
+            HandType::HighCard => 1,
+        }
+    }
+
+    fn get_point_by_card(&self, card: char) -> u32 {
+        match card {
+            '2' => 2,
+            '3' => 3,
+            '4' => 4,
+            '5' => 5,
+            '6' => 6,
+            '7' => 7,
+            '8' => 8,
+            '9' => 9,
+            'T' => 10,
+            'J' => match self.joker_mode {
+                false => 11,
+                true => 1,
+            },
+            'Q' => 12,
+            'K' => 13,
+            'A' => 14,
+            // `Hand::from_str` only accepts the cards above
+            _ => unreachable!("ERROR: a hand only has the cards 23456789TJQKA"),
+        }
+    }
+
+    fn change_hand_type_by_joker(&mut self) {
+        let card_counts = self.cards.iter().counts();
+        let num_of_jokers = card_counts.get(&'J').unwrap_or(&0);
+        //  contains the counts for other types of cards
+        let counts = card_counts
+            .iter()
+            .filter(|&(&k, _)| *k != 'J')
+            .map(|(_, v)| *v)
+            .collect::<Vec<usize>>();
+
+        self.hand_type = match (*counts.iter().max().unwrap_or(&0), *num_of_jokers) {
+            (a, b) if a + b == 5 => HandType::FiveOfKind,
+            (a, b) if a + b == 4 => HandType::FourOfKind,
+            (_, 0) => self.hand_type,
+            (2, _) => {
+                let pairs = counts.iter().filter(|&&v| v == 2).count();
+                match (pairs, *num_of_jokers) {
+                    (2, 1) => HandType::FullHouse,
+                    (1, 1) => HandType::ThreeOfKind,
+                    // with 5 cards, two pairs leave 1 joker and one pair 1
+                    // or 2, and 2 jokers already made four of a kind
+                    _ => unreachable!("ERROR: a hand has 5 cards"),
+                }
+            }
+            (1, 2) => HandType::ThreeOfKind,
+            (1, 1) => HandType::OnePair,
+            // `Hand::from_str` checks there are 5 cards, so any other mix
+            // adds up to four or five of a kind above
+            _ => unreachable!("ERROR: a hand has 5 cards"),
+        };
+    }
+}
+
+impl Ord for Hand {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let us_point_by_hand = self.get_point_by_hand_type();
+        let other_point_by_hand = other.get_point_by_hand_type();
+        match us_point_by_hand.cmp(&other_point_by_hand) {
+            Ordering::Greater | Ordering::Less => us_point_by_hand.cmp(&other_point_by_hand),
+            Ordering::Equal => {
+                for index in 0..self.cards.len() {
+                    let us_point_by_card = self.get_point_by_card(self.cards[index]);
+                    let other_point_by_card = self.get_point_by_card(other.cards[index]);
+                    if us_point_by_card != other_point_by_card {
+                        return us_point_by_card.cmp(&other_point_by_card);
+                    }
+                }
+                Ordering::Equal
+            }
+        }
+    }
+}
+
+impl PartialOrd for Hand {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl PartialEq for Hand {
+    fn eq(&self, other: &Self) -> bool {
+        self.cards == other.cards && self.bet == other.bet && self.hand_type == other.hand_type
+    }
+}
+
+impl Eq for Hand {}
+
+impl FromStr for Hand {
+    type Err = Error;
+
+    fn from_str(line: &str) -> Result<Self> {
+        let (cards, bet) = error::split_once(line, line, " ")?;
+        if let Some((i, _)) = cards
+            .char_indices()
+            .find(|&(_, c)| !"23456789TJQKA".contains(c))
+        {
+            return Err(error::unexpected_char(line, cards, i));
+        }
+        if cards.len() != 5 {
+            return Err(Error::at(line, cards, "expected 5 cards"));
+        }
+        let cards = cards.chars().collect_vec();
+        // cards.reverse();
+
+        let mut appearances = cards
+            .iter()
+            .fold(HashMap::new(), |mut acc: HashMap<char, usize>, char| {
+                *acc.entry(*char).or_insert(0) += 1;
+                acc
+            })
+            .values()
+            .copied()
+            .collect_vec();
+
+        appearances.sort_unstable();
+        appearances.reverse();
+
+        let hand_type = match appearances.len() {
+            1 => HandType::FiveOfKind,
+            2 => {
+                if appearances.contains(&4) {
+                    HandType::FourOfKind
+                } else {
+                    HandType::FullHouse
+                }
+            }
+            3 => {
+                if appearances.contains(&3) {
+                    HandType::ThreeOfKind
+                } else {
+                    HandType::TwoPair
+                }
+            }
+            4 => HandType::OnePair,
+            5 => HandType::HighCard,
+            _ => unreachable!("ERROR: a hand has 5 cards"),
+        };
+        Ok(Self {
+            cards,
+            bet: error::parse(line, bet)?,
+            hand_type,
+            joker_mode: false,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    const TEST_INPUT: &str = include_str!("../inputs/day-7-test.txt");
+    #[test]
+    fn test_input() {
+        assert_eq!(Day07::solve_part_1(TEST_INPUT).unwrap(), 6_440);
+        assert_eq!(Day07::solve_part_2(TEST_INPUT).unwrap(), 5_905);
+    }
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(
+            Day07::solve_part_1(include_str!("../inputs/day-7.txt")).unwrap(),
+            250_602_641
+        );
+    }
+
+    #[test]
+    fn test_part_2() {
+        assert_eq!(
+            Day07::solve_part_2(include_str!("../inputs/day-7.txt")).unwrap(),
+            251_037_509
+        );
+    }
+}
@@ -0,0 +1,242 @@
+//! Day 8: Haunted Wasteland
+
+use crate::{
+    err, error,
+    solution::Solution,
+    utils::{cycle, first_alignment},
+    Error, Result,
+};
+use itertools::Itertools;
+
+use std::{collections::HashMap, str::FromStr};
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+    type Input = (Vec<char>, Network);
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<(Vec<char>, Network)> {
+        let (direction, network) = error::split_once(input, input, "\n\n")?;
+        if direction.is_empty() {
+            return Err(Error::at(input, direction, "expected L and R moves"));
+        }
+        if let Some((i, _)) = direction
+            .char_indices()
+            .find(|&(_, c)| c != 'L' && c != 'R')
+        {
+            return Err(error::unexpected_char(input, direction, i));
+        }
+        let network = network
+            .parse::<Network>()
+            .map_err(|e| e.within(input, network))?;
+        Ok((direction.chars().collect_vec(), network))
+    }
+
+    fn part_1((moves, network): &(Vec<char>, Network)) -> Result<usize> {
+        if !network.nodes.contains_key("AAA") {
+            return err!(Assumption, "the network should contain AAA");
+        }
+        let mut state = ("AAA", 0);
+        // by then some (node, move) came back, so ZZZ never will
+        for steps in 1..=network.nodes.len() * moves.len() {
+            state = step(network, moves, state);
+            if state.0 == "ZZZ" {
+                return Ok(steps);
+            }
+        }
+        err!(Unsolvable, "ZZZ cannot be reached from AAA")
+    }
+
+    fn part_2((moves, network): &(Vec<char>, Network)) -> Result<usize> {
+        let ghosts = network
+            .nodes
+            .keys()
+            .filter(|&k| k.ends_with('A'))
+            .map(|start| Ghost::follow(network, moves, start))
+            .collect_vec();
+        if ghosts.is_empty() {
+            return err!(Assumption, "the network should contain nodes ending with A");
+        }
+
+        // a time some ghost is on Z only once, before going round its cycle
+        let once = ghosts
+            .iter()
+            .flat_map(|ghost| &ghost.once)
+            .copied()
+            .filter(|&t| ghosts.iter().all(|ghost| ghost.on_z(t)))
+            .min();
+        // or a time every ghost is on Z within its cycle, for any choice of
+        // the Z hit of each ghost
+        let cycling = ghosts
+            .iter()
+            .map(|ghost| ghost.cycle.iter().map(|&hit| (hit, ghost.period)))
+            .multi_cartesian_product()
+            .filter_map(|cycles| first_alignment(&cycles))
+            .min();
+        once.into_iter()
+            .chain(cycling)
+            .min()
+            .ok_or_else(|| Error::Unsolvable(String::from("the ghosts never all end on Z")))
+    }
+}
+
+// The node after `node`, and the move after `i`.
+fn step<'a>(network: &'a Network, moves: &[char], (node, i): (&'a str, usize)) -> (&'a str, usize) {
+    let (left, right) = &network.nodes[node];
+    let next = if moves[i] == 'L' { left } else { right };
+    (next, (i + 1) % moves.len())
+}
+
+// When a ghost is on a node ending with Z.
+struct Ghost {
+    // the first move of the cycle of its (node, move) states, never the start
+    // itself, and how many moves the cycle takes
+    first: usize,
+    period: usize,
+    // the times before the cycle
+    once: Vec<usize>,
+    // the times within the first round of the cycle, which come back every
+    // period moves
+    cycle: Vec<usize>,
+}
+
+impl Ghost {
+    fn follow<'a>(network: &'a Network, moves: &[char], start: &'a str) -> Self {
+        let next = |&state: &(&'a str, usize)| step(network, moves, state);
+        let (mu, period) = cycle::hashed((start, 0), next, |&state| state);
+        let first = mu.max(1);
+        let (mut once, mut cycle) = (Vec::new(), Vec::new());
+        let mut state = (start, 0);
+        for t in 1..first + period {
+            state = next(&state);
+            if state.0.ends_with('Z') {
+                if t < first {
+                    once.push(t);
+                } else {
+                    cycle.push(t);
+                }
+            }
+        }
+        Self {
+            first,
+            period,
+            once,
+            cycle,
+        }
+    }
+
+    fn on_z(&self, t: usize) -> bool {
+        self.once.contains(&t)
+            || (t >= self.first
+                && self
+                    .cycle
+                    .contains(&(self.first + (t - self.first) % self.period)))
+    }
+}
+
+/// The left and right node of every node.
+#[derive(Debug)]
+pub struct Network {
+    nodes: HashMap<String, (String, String)>,
+}
+
+impl FromStr for Network {
+    type Err = Error;
+
+    fn from_str(input: &str) -> Result<Self> {
+        let nodes = input
+            .lines()
+            .map(|line| {
+                let (from, to) = error::split_once(input, line, " = ")?;
+                let (left, right) = error::split_once(input, to, ", ")?;
+                Ok((
+                    from,
+                    (left.trim_start_matches('('), right.trim_end_matches(')')),
+                ))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        // every node we can move to must exist
+        let known = nodes.iter().map(|&(from, _)| from).collect::<Vec<_>>();
+        if let Some(&unknown) = nodes
+            .iter()
+            .flat_map(|(_, (left, right))| [left, right])
+            .find(|node| !known.contains(node))
+        {
+            return Err(Error::at(input, unknown, format!("unknown node {unknown}")));
+        }
+        Ok(Network {
+            nodes: nodes
+                .into_iter()
+                .map(|(from, (left, right))| {
+                    (
+                        String::from(from),
+                        (String::from(left), String::from(right)),
+                    )
+                })
+                .collect::<HashMap<String, (String, String)>>(),
+        })
+    }
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input() {
+        assert_eq!(
+            Day08::solve_part_1(include_str!("../inputs/day-8-test-1.txt")).unwrap(),
+            2
+        );
+        assert_eq!(
+            Day08::solve_part_1(include_str!("../inputs/day-8-test-2.txt")).unwrap(),
+            6
+        );
+        assert_eq!(
+            Day08::solve_part_2(include_str!("../inputs/day-8-test-3.txt")).unwrap(),
+            6
+        );
+    }
+
+    #[test]
+    fn test_ghosts_never_align() {
+        // 11A is on Z after the first and second of every 3 moves, 22A after the
+        // third only
+        let input = "\
+L
+
+11A = (11Z, 11Z)
+11Z = (22Z, 22Z)
+22Z = (11B, 11B)
+11B = (11Z, 11Z)
+22A = (22B, 22B)
+22B = (22C, 22C)
+22C = (33Z, 33Z)
+33Z = (22B, 22B)
+AAA = (ZZZ, ZZZ)
+ZZZ = (ZZZ, ZZZ)";
+        assert_eq!(Day08::solve_part_1(input).unwrap(), 1);
+        assert!(matches!(
+            Day08::solve_part_2(input),
+            Err(Error::Unsolvable(_))
+        ));
+    }
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(
+            Day08::solve_part_1(include_str!("../inputs/day-8.txt")).unwrap(),
+            11_911
+        );
+    }
+
+    #[test]
+    fn test_part_2() {
+        assert_eq!(
+            Day08::solve_part_2(include_str!("../inputs/day-8.txt")).unwrap(),
+            10_151_663_816_849
+        );
+    }
+}
@@ -0,0 +1,66 @@
+//! Day 9: Mirage Maintenance
+
+use crate::{error, solution::Solution, utils::poly, Error, Result};
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+    type Input = Vec<Vec<i64>>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Vec<Vec<i64>>> {
+        input.lines().map(|line| parse_line(input, line)).collect()
+    }
+
+    fn part_1(histories: &Vec<Vec<i64>>) -> Result<i64> {
+        Ok(histories
+            .iter()
+            .map(|numbers| poly::extrapolate(numbers, numbers.len() as i64))
+            .sum())
+    }
+
+    fn part_2(histories: &Vec<Vec<i64>>) -> Result<i64> {
+        Ok(histories
+            .iter()
+            .map(|numbers| poly::extrapolate(numbers, -1))
+            .sum())
+    }
+}
+
+fn parse_line(input: &str, line: &str) -> Result<Vec<i64>> {
+    if line.trim().is_empty() {
+        return Err(Error::at(input, line, "expected a history"));
+    }
+    line.split_ascii_whitespace()
+        .map(|number| error::parse::<i64>(input, number))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    const TEST_INPUT: &str = include_str!("../inputs/day-9-test.txt");
+    #[test]
+    fn test_input() {
+        assert_eq!(Day09::solve_part_1(TEST_INPUT).unwrap(), 114);
+        assert_eq!(Day09::solve_part_2(TEST_INPUT).unwrap(), 2);
+    }
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(
+            Day09::solve_part_1(include_str!("../inputs/day-9.txt")).unwrap(),
+            1_681_758_908
+        );
+    }
+
+    #[test]
+    fn test_part_2() {
+        assert_eq!(
+            Day09::solve_part_2(include_str!("../inputs/day-9.txt")).unwrap(),
+            803
+        );
+    }
+}
@@ -0,0 +1,214 @@
+//! Day 10: Pipe Maze
+
+use crate::{
+    err,
+    solution::Solution,
+    utils::{search, Direction, Grid, Point, Polygon, Pos},
+    Error, Result,
+};
+
+use std::{fmt::Display, str::FromStr};
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    type Input = Maze;
+    type Part1 = usize;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Maze> {
+        input.parse()
+    }
+
+    fn part_1(maze: &Maze) -> Result<usize> {
+        // the farthest tile along the pipes joined to the start, either way
+        // round the loop
+        let start = maze.get_start_point()?;
+        let steps = search::reachable(start, |&pos| maze.joined(pos), usize::MAX);
+        Ok(steps.into_values().max().unwrap_or_default())
+    }
+
+    fn part_2(maze: &Maze) -> Result<i64> {
+        // the enclosed tiles are the lattice points inside the loop
+        let path = find_loop(maze)?;
+        let vertices = path
+            .into_iter()
+            .map(|(row, col)| Point::new(col as i64, row as i64))
+            .collect();
+        Ok(Polygon::new(vertices).interior())
+    }
+}
+
+// The tiles of the loop through the start, in order.
+fn find_loop(maze: &Maze) -> Result<Vec<Pos>> {
+    let start = maze.get_start_point()?;
+    // any pipe next to the start leading back to it
+    let Some(mut direction) = Direction::ALL.into_iter().find(|&direction| {
+        maze.tiles
+            .towards(start, direction)
+            .is_some_and(|next| maze.tiles[next].connects(direction.opposite()))
+    }) else {
+        return err!(Unsolvable, "there are no pipes next to the start");
+    };
+
+    let mut path = vec![start];
+    let mut pos = start;
+    loop {
+        let Some(next) = maze.tiles.towards(pos, direction) else {
+            return err!(Unsolvable, "the loop leaves the maze at {pos:?}");
+        };
+        if next == start {
+            return Ok(path);
+        }
+        // out through the other end of the pipe
+        let came_from = direction.opposite();
+        let tile = maze.tiles[next];
+        let Some(&out) = tile
+            .directions()
+            .iter()
+            .find(|&&d| d != came_from)
+            .filter(|_| tile.connects(came_from))
+        else {
+            return err!(Unsolvable, "the loop is broken at {next:?}");
+        };
+        path.push(next);
+        pos = next;
+        direction = out;
+    }
+}
+
+/// The field of pipes, with the animal's start.
+pub struct Maze {
+    tiles: Grid<Tile>,
+}
+
+impl Maze {
+    fn get_start_point(&self) -> Result<Pos> {
+        self.tiles
+            .position(|tile| *tile == Tile::Start)
+            .ok_or_else(|| Error::Assumption(String::from("the maze should have a start")))
+    }
+
+    // The tiles next to `pos` that its pipe and theirs join it to.
+    fn joined(&self, pos: Pos) -> Vec<Pos> {
+        self.tiles[pos]
+            .directions()
+            .iter()
+            .filter_map(|&direction| {
+                let next = self.tiles.towards(pos, direction)?;
+                self.tiles[next]
+                    .connects(direction.opposite())
+                    .then_some(next)
+            })
+            .collect()
+    }
+}
+#[derive(PartialEq, Clone, Copy)]
+enum Tile {
+    Vertical,
+    Horizontal,
+    NorthWest,
+    NorthEast,
+    SouthWest,
+    SouthEast,
+    Ground,
+    Start,
+}
+
+impl Tile {
+    // The directions the pipe leads to, every one for the start.
+    fn directions(self) -> &'static [Direction] {
+        use Direction::*;
+        match self {
+            Tile::Vertical => &[North, South],
+            Tile::Horizontal => &[West, East],
+            Tile::NorthWest => &[North, West],
+            Tile::NorthEast => &[North, East],
+            Tile::SouthWest => &[South, West],
+            Tile::SouthEast => &[South, East],
+            Tile::Start => &Direction::ALL,
+            Tile::Ground => &[],
+        }
+    }
+
+    fn connects(self, direction: Direction) -> bool {
+        self.directions().contains(&direction)
+    }
+}
+
+impl Display for Tile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Tile::Vertical => write!(f, "|"),
+            Tile::Horizontal => write!(f, "-"),
+            Tile::NorthWest => write!(f, "J"),
+            Tile::NorthEast => write!(f, "L"),
+            Tile::SouthWest => write!(f, "7"),
+            Tile::SouthEast => write!(f, "F"),
+            Tile::Ground => write!(f, "."),
+            Tile::Start => write!(f, "S"),
+        }
+    }
+}
+
+impl Display for Maze {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.tiles)
+    }
+}
+
+impl FromStr for Maze {
+    type Err = Error;
+
+    fn from_str(input: &str) -> Result<Self> {
+        let tiles = Grid::parse(input, |tile| match tile {
+            '|' => Some(Tile::Vertical),
+            '-' => Some(Tile::Horizontal),
+            'L' => Some(Tile::NorthEast),
+            'J' => Some(Tile::NorthWest),
+            '7' => Some(Tile::SouthWest),
+            'F' => Some(Tile::SouthEast),
+            '.' => Some(Tile::Ground),
+            'S' => Some(Tile::Start),
+            _ => None,
+        })?;
+        Ok(Self { tiles })
+    }
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input() {
+        assert_eq!(
+            Day10::solve_part_1(include_str!("../inputs/day-10-test-1.txt")).unwrap(),
+            8
+        );
+        assert_eq!(
+            Day10::solve_part_1(include_str!("../inputs/day-10-test-2.txt")).unwrap(),
+            4
+        );
+        assert_eq!(
+            Day10::solve_part_2(include_str!("../inputs/day-10-test-3.txt")).unwrap(),
+            4
+        );
+    }
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(
+            Day10::solve_part_1(include_str!("../inputs/day-10.txt")).unwrap(),
+            6_754
+        );
+    }
+
+    #[test]
+    fn test_part_2() {
+        assert_eq!(
+            Day10::solve_part_2(include_str!("../inputs/day-10.txt")).unwrap(),
+            567
+        );
+    }
+}
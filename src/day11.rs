@@ -0,0 +1,109 @@
+//! Day 11: Cosmic Expansion
+
+use crate::{solution::Solution, utils::Grid, Error, Result};
+use itertools::Itertools;
+
+use std::{collections::HashSet, str::FromStr};
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    type Input = Image;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Image> {
+        input.parse()
+    }
+
+    fn part_1(image: &Image) -> Result<usize> {
+        expand(image, 2)
+    }
+
+    fn part_2(image: &Image) -> Result<usize> {
+        expand(image, 1_000_000)
+    }
+}
+
+fn expand(image: &Image, factor: usize) -> Result<usize> {
+    Ok(image
+        .galaxies
+        .iter()
+        .tuple_combinations()
+        .map(|(p1, p2)| {
+            let mut distance = ((p1.0 - p2.0).abs() + (p1.1 - p2.1).abs()) as usize;
+            let expand_factor = factor - 1;
+            let (from_row, to_row) = (std::cmp::min(p1.0, p2.0), std::cmp::max(p1.0, p2.0));
+            let (from_col, to_col) = (std::cmp::min(p1.1, p2.1), std::cmp::max(p1.1, p2.1));
+
+            for row in from_row + 1..to_row {
+                if image.grid.row(row as usize).iter().any(|tile| *tile != '.') {
+                    continue;
+                }
+                distance += expand_factor;
+            }
+
+            for col in from_col + 1..to_col {
+                if image.grid.column(col as usize).any(|tile| *tile != '.') {
+                    continue;
+                }
+                distance += expand_factor;
+            }
+            distance
+        })
+        .sum::<usize>())
+}
+
+/// The galaxies, and the rows and columns without any.
+#[derive(Clone)]
+pub struct Image {
+    grid: Grid<char>,
+    galaxies: HashSet<(i32, i32)>,
+}
+
+impl FromStr for Image {
+    type Err = Error;
+
+    fn from_str(input: &str) -> Result<Self> {
+        let grid = Grid::parse(input, |c| matches!(c, '.' | '#').then_some(c))?;
+        if grid.height() == 0 {
+            return Err(Error::at(input, input, "expected an image"));
+        }
+        let galaxies = grid
+            .iter()
+            .filter(|(_, &c)| c == '#')
+            .map(|((y, x), _)| (y as i32, x as i32))
+            .collect();
+
+        Ok(Self { grid, galaxies })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    const TEST_INPUT: &str = include_str!("../inputs/day-11-test.txt");
+    #[test]
+    fn test_input() {
+        assert_eq!(Day11::solve_part_1(TEST_INPUT).unwrap(), 374);
+        // in the example it is 10 and 1_000 times
+        assert_eq!(Day11::solve_part_2(TEST_INPUT).unwrap(), 82_000_210);
+    }
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(
+            Day11::solve_part_1(include_str!("../inputs/day-11.txt")).unwrap(),
+            9_769_724
+        );
+    }
+
+    #[test]
+    fn test_part_2() {
+        assert_eq!(
+            Day11::solve_part_2(include_str!("../inputs/day-11.txt")).unwrap(),
+            603_020_563_700
+        );
+    }
+}
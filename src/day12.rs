@@ -0,0 +1,174 @@
+//! Day 12: Hot Springs
+
+use crate::{error, solution::Solution, Error, Result};
+
+use std::{collections::HashMap, str::FromStr};
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    type Input = Vec<Spring>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<Spring>> {
+        input
+            .lines()
+            .map(|line| line.parse::<Spring>().map_err(|e| e.within(input, line)))
+            .collect()
+    }
+
+    fn part_1(springs: &Vec<Spring>) -> Result<usize> {
+        Ok(springs.iter().map(|spring| spring.clone().score(0)).sum())
+    }
+
+    fn part_2(springs: &Vec<Spring>) -> Result<usize> {
+        Ok(springs.iter().map(|spring| spring.clone().score(5)).sum())
+    }
+}
+
+/// One row of springs and the sizes of its damaged groups.
+#[derive(Clone)]
+pub struct Spring {
+    pattern: Vec<Condition>,
+    sizes: Vec<usize>,
+}
+
+/// What is known about a spring.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Condition {
+    Operational,
+    Damaged,
+    Unknown,
+}
+
+impl Spring {
+    fn score(&mut self, scale_factor: usize) -> usize {
+        let mut cache = HashMap::new();
+        if scale_factor != 0 {
+            self.scale(scale_factor);
+        }
+        Self::arrange(&self.pattern, &self.sizes, &mut cache)
+    }
+
+    fn arrange(
+        pattern: &[Condition],
+        sizes: &[usize],
+        cache: &mut HashMap<(usize, usize), usize>,
+    ) -> usize {
+        if let Some(arrangements) = cache.get(&(pattern.len(), sizes.len())) {
+            return *arrangements;
+        }
+        if sizes.is_empty() {
+            return (!pattern.contains(&Condition::Damaged)) as usize;
+        }
+
+        let min_remaining = sizes.iter().sum::<usize>() + sizes.len() - 1;
+
+        if pattern.len() < min_remaining {
+            return 0;
+        }
+
+        let result = match pattern[0] {
+            Condition::Operational => Self::arrange(&pattern[1..], sizes, cache),
+            Condition::Damaged => Self::arrange_hash(pattern, sizes, cache),
+            Condition::Unknown => {
+                Self::arrange(&pattern[1..], sizes, cache)
+                    + Self::arrange_hash(pattern, sizes, cache)
+            }
+        };
+        cache.insert((pattern.len(), sizes.len()), result);
+        result
+    }
+    fn arrange_hash(
+        pattern: &[Condition],
+        sizes: &[usize],
+        cache: &mut HashMap<(usize, usize), usize>,
+    ) -> usize {
+        if pattern.len() < sizes[0] || pattern[0..sizes[0]].contains(&Condition::Operational) {
+            return 0;
+        }
+        if pattern.len() == sizes[0] {
+            return (sizes.len() == 1) as usize;
+        }
+        if pattern[sizes[0]] == Condition::Damaged {
+            return 0;
+        }
+
+        Self::arrange(&pattern[sizes[0] + 1..], &sizes[1..], cache)
+    }
+    fn scale(&mut self, scale_factor: usize) {
+        let mut pattern: Vec<Condition> = Vec::new();
+        for _ in 0..scale_factor - 1 {
+            pattern.extend(self.pattern.iter().chain([&Condition::Unknown]));
+        }
+        let mut sizes: Vec<usize> = Vec::new();
+        pattern.extend(self.pattern.iter());
+        for _ in 0..scale_factor {
+            sizes.extend(self.sizes.iter());
+        }
+        self.pattern = pattern;
+        self.sizes = sizes;
+    }
+}
+
+impl FromStr for Spring {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (pattern, nums) = error::split_once(s, s, " ")?;
+        let pattern = pattern
+            .char_indices()
+            .map(|(i, c)| match c {
+                '.' => Ok(Condition::Operational),
+                '#' => Ok(Condition::Damaged),
+                '?' => Ok(Condition::Unknown),
+                _ => Err(error::unexpected_char(s, pattern, i)),
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let sizes = nums
+            .split(',')
+            .map(|n| match error::parse::<usize>(s, n)? {
+                0 => Err(Error::at(s, n, "groups should have at least 1 spring")),
+                size => Ok(size),
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self { pattern, sizes })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    const TEST_INPUT: &str = include_str!("../inputs/day-12-test.txt");
+    #[test]
+    fn test_parse() {
+        assert!(matches!(
+            Day12::solve_part_1("??? 1,0"),
+            Err(Error::Parse { .. })
+        ));
+    }
+
+    #[test]
+    fn test_input() {
+        assert_eq!(Day12::solve_part_1(TEST_INPUT).unwrap(), 21);
+        assert_eq!(Day12::solve_part_2(TEST_INPUT).unwrap(), 525_152);
+    }
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(
+            Day12::solve_part_1(include_str!("../inputs/day-12.txt")).unwrap(),
+            7_843
+        );
+    }
+
+    #[test]
+    fn test_part_2() {
+        assert_eq!(
+            Day12::solve_part_2(include_str!("../inputs/day-12.txt")).unwrap(),
+            10_153_896_718_999
+        );
+    }
+}
@@ -0,0 +1,97 @@
+//! Day 13: Point of Incidence
+
+use crate::{input, solution::Solution, utils::Grid, Error, Result};
+
+pub type Pattern = Grid<u8>;
+
+// brute force everything.
+// start from row 0 -> second to last row.
+// then start from col 0 -> second to last col.
+// target is number of different tile (0 part 1 and 1 part 2)
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+    type Input = Vec<Pattern>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<Pattern>> {
+        input::blocks(input)
+            .map(|s| parse_pattern(input, s))
+            .collect()
+    }
+
+    fn part_1(grids: &Vec<Pattern>) -> Result<usize> {
+        solve(grids, 0)
+    }
+
+    fn part_2(grids: &Vec<Pattern>) -> Result<usize> {
+        solve(grids, 1)
+    }
+}
+
+fn parse_pattern(input: &str, s: &str) -> Result<Pattern> {
+    let pattern = Grid::parse_within(input, s, |c| matches!(c, '.' | '#').then_some(c as u8))?;
+    if pattern.width() == 0 {
+        return Err(Error::at(input, s, "expected a pattern"));
+    }
+    Ok(pattern)
+}
+
+// A column mirror is a row mirror of the transposed pattern.
+fn find_col(grid: &Pattern, limit: usize) -> Option<usize> {
+    find_row(&grid.transpose(), limit)
+}
+
+fn find_row(grid: &Pattern, limit: usize) -> Option<usize> {
+    (0..grid.height() - 1).find(|&r| {
+        let num_of_diff_tiles = (0..=r.min(grid.height() - r - 2))
+            .map(|dr| {
+                let (a, b) = (grid.row(r - dr), grid.row(r + 1 + dr));
+                a.iter().zip(b).filter(|(a, b)| a != b).count()
+            })
+            .sum::<usize>();
+        num_of_diff_tiles == limit
+    })
+}
+
+fn solve(grids: &[Pattern], limit: usize) -> Result<usize> {
+    grids
+        .iter()
+        .enumerate()
+        .map(|(i, grid)| {
+            find_row(grid, limit)
+                .map(|r| (r + 1) * 100)
+                .or_else(|| find_col(grid, limit).map(|c| c + 1))
+                .ok_or_else(|| Error::Unsolvable(format!("pattern {} has no mirror", i + 1)))
+        })
+        .sum()
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    const TEST_INPUT: &str = include_str!("../inputs/day-13-test.txt");
+    #[test]
+    fn test_input() {
+        assert_eq!(Day13::solve_part_1(TEST_INPUT).unwrap(), 405);
+        assert_eq!(Day13::solve_part_2(TEST_INPUT).unwrap(), 400);
+    }
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(
+            Day13::solve_part_1(include_str!("../inputs/day-13.txt")).unwrap(),
+            39_939
+        );
+    }
+
+    #[test]
+    fn test_part_2() {
+        assert_eq!(
+            Day13::solve_part_2(include_str!("../inputs/day-13.txt")).unwrap(),
+            32_069
+        );
+    }
+}
@@ -0,0 +1,229 @@
+//! Day 14: Parabolic Reflector Dish
+
+use crate::{
+    solution::Solution,
+    utils::{cycle, Grid},
+    Error, Result,
+};
+
+use std::{
+    fmt::Display,
+    hash::{DefaultHasher, Hash, Hasher},
+    str::FromStr,
+};
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+    type Input = Dish;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Dish> {
+        input.parse()
+    }
+
+    fn part_1(dish: &Dish) -> Result<usize> {
+        let mut dish = dish.clone();
+        move_vertical(&mut dish, -1);
+
+        Ok(calculate_score(&dish.grid))
+    }
+
+    fn part_2(dish: &Dish) -> Result<usize> {
+        let dish = cycle::nth(
+            dish.clone(),
+            |dish| {
+                let mut dish = dish.clone();
+                run_a_cycle(&mut dish);
+                dish
+            },
+            // a hash of the grid, to not keep a copy of every one
+            |dish| {
+                let mut hasher = DefaultHasher::new();
+                dish.grid.hash(&mut hasher);
+                hasher.finish()
+            },
+            1_000_000_000,
+        );
+        Ok(calculate_score(&dish.grid))
+    }
+}
+
+fn run_a_cycle(dish: &mut Dish) {
+    move_vertical(dish, -1);
+    move_horizontal(dish, -1);
+    move_vertical(dish, 1);
+    move_horizontal(dish, 1);
+}
+
+fn move_horizontal(dish: &mut Dish, direction: i32) {
+    if direction > 0 {
+        for row in 0..dish.grid.height() {
+            for col in (0..dish.grid.width()).rev() {
+                if dish.grid[(row, col)].is_rounded() {
+                    let mut idx = col;
+                    while idx < dish.grid.width() - 1 {
+                        if dish.grid[(row, idx + 1)].is_space() {
+                            idx += 1;
+                        } else {
+                            break;
+                        }
+                    }
+                    if idx != col {
+                        dish.grid[(row, idx)] = Tile::Rounded;
+                        dish.grid[(row, col)] = Tile::Space;
+                    }
+                }
+            }
+        }
+    } else {
+        for row in 0..dish.grid.height() {
+            for col in 0..dish.grid.width() {
+                if dish.grid[(row, col)].is_rounded() {
+                    let mut idx = col;
+                    while idx >= 1 {
+                        if dish.grid[(row, idx - 1)].is_space() {
+                            idx -= 1;
+                        } else {
+                            break;
+                        }
+                    }
+                    if idx != col {
+                        dish.grid[(row, idx)] = Tile::Rounded;
+                        dish.grid[(row, col)] = Tile::Space;
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn move_vertical(dish: &mut Dish, direction: i32) {
+    if direction < 0 {
+        for row in 0..dish.grid.height() {
+            for col in 0..dish.grid.width() {
+                if dish.grid[(row, col)].is_rounded() {
+                    let mut idx = row;
+                    while idx >= 1 {
+                        if dish.grid[(idx - 1, col)].is_space() {
+                            idx -= 1;
+                        } else {
+                            break;
+                        }
+                    }
+                    if idx != row {
+                        dish.grid[(idx, col)] = Tile::Rounded;
+                        dish.grid[(row, col)] = Tile::Space;
+                    }
+                }
+            }
+        }
+    } else {
+        for row in (0..dish.grid.height()).rev() {
+            for col in 0..dish.grid.width() {
+                if dish.grid[(row, col)].is_rounded() {
+                    let mut idx = row;
+                    while idx < dish.grid.height() - 1 {
+                        if dish.grid[(idx + 1, col)].is_space() {
+                            idx += 1;
+                        } else {
+                            break;
+                        }
+                    }
+                    if idx != row {
+                        dish.grid[(idx, col)] = Tile::Rounded;
+                        dish.grid[(row, col)] = Tile::Space;
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn calculate_score(grid: &Grid<Tile>) -> usize {
+    grid.iter()
+        .filter(|(_, tile)| tile.is_rounded())
+        .map(|((y, _), _)| grid.height() - y)
+        .sum()
+}
+
+impl Display for Dish {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.grid)
+    }
+}
+
+impl Display for Tile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Tile::Squared => write!(f, "#"),
+            Tile::Rounded => write!(f, "O"),
+            Tile::Space => write!(f, "."),
+        }
+    }
+}
+
+/// The rocks on the platform.
+#[derive(Clone)]
+pub struct Dish {
+    grid: Grid<Tile>,
+}
+
+impl FromStr for Dish {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let grid = Grid::parse(s, |c| match c {
+            '#' => Some(Tile::Squared),
+            'O' => Some(Tile::Rounded),
+            '.' => Some(Tile::Space),
+            _ => None,
+        })?;
+        Ok(Self { grid })
+    }
+}
+
+impl Tile {
+    fn is_rounded(&self) -> bool {
+        *self == Tile::Rounded
+    }
+    fn is_space(&self) -> bool {
+        *self == Tile::Space
+    }
+}
+
+#[derive(PartialEq, Clone, Copy, Eq, Hash)]
+enum Tile {
+    Squared,
+    Rounded,
+    Space,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    const TEST_INPUT: &str = include_str!("../inputs/day-14-test.txt");
+    #[test]
+    fn test_input() {
+        assert_eq!(Day14::solve_part_1(TEST_INPUT).unwrap(), 136);
+        assert_eq!(Day14::solve_part_2(TEST_INPUT).unwrap(), 64);
+    }
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(
+            Day14::solve_part_1(include_str!("../inputs/day-14.txt")).unwrap(),
+            108_857
+        );
+    }
+
+    #[test]
+    fn test_part_2() {
+        assert_eq!(
+            Day14::solve_part_2(include_str!("../inputs/day-14.txt")).unwrap(),
+            95_273
+        );
+    }
+}
@@ -0,0 +1,132 @@
+//! Day 15: Lens Library
+
+use crate::{error, solution::Solution, Error, Result};
+
+use std::collections::VecDeque;
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+    type Input = Vec<String>;
+    type Part1 = i32;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<String>> {
+        input
+            .split(',')
+            .map(|step| {
+                // either `label=focal length` or `label-`
+                match step.split_once('=') {
+                    Some((_, focal_length)) => {
+                        error::parse::<u8>(input, focal_length)?;
+                    }
+                    None if step.ends_with('-') => {}
+                    None => {
+                        return Err(Error::at(input, &step[step.len()..], "expected '=' or '-'"))
+                    }
+                }
+                Ok(String::from(step))
+            })
+            .collect()
+    }
+
+    fn part_1(steps: &Vec<String>) -> Result<i32> {
+        part_1(steps)
+    }
+
+    fn part_2(steps: &Vec<String>) -> Result<usize> {
+        part_2(steps)
+    }
+}
+
+fn part_1(steps: &[String]) -> Result<i32> {
+    Ok(steps.iter().map(|step| hash(step)).sum())
+}
+
+fn part_2(steps: &[String]) -> Result<usize> {
+    Ok(steps
+        .iter()
+        .try_fold(
+            vec![VecDeque::default(); 256],
+            |mut acc: Vec<VecDeque<(&str, u8)>>, len| {
+                let (label, focal_length) = match len.split_once('=') {
+                    Some((label, focal_length)) => (
+                        label,
+                        focal_length.parse::<u8>().map_err(|_| {
+                            Error::Assumption(format!("{len} should have a focal length"))
+                        })?,
+                    ),
+                    None => (len.trim_end_matches('-'), 0),
+                };
+                let box_index = hash(label);
+
+                if focal_length == 0 {
+                    if let Some(pos) = acc[box_index as usize]
+                        .iter()
+                        .position(|&(l, _)| l == label)
+                    {
+                        acc[box_index as usize].remove(pos);
+                    }
+                } else if let Some(pos) = acc[box_index as usize]
+                    .iter()
+                    .position(|&(l, _)| l == label)
+                {
+                    acc[box_index as usize][pos] = (label, focal_length);
+                } else {
+                    acc[box_index as usize].push_back((label, focal_length));
+                }
+                Ok::<_, Error>(acc)
+            },
+        )?
+        .iter()
+        .enumerate()
+        .filter_map(|(index, b)| {
+            if !b.is_empty() {
+                Some(
+                    (index + 1)
+                        * b.iter()
+                            .enumerate()
+                            .map(|(i, v)| (i + 1) * (v.1 as usize))
+                            .sum::<usize>(),
+                )
+            } else {
+                None
+            }
+        })
+        .sum::<usize>())
+}
+
+fn hash(data: &str) -> i32 {
+    data.chars().fold(0, |acc, c| {
+        let number = (c as u8) as i32;
+        ((acc + number) * 17) % 256
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    const TEST_INPUT: &str = include_str!("../inputs/day-15-test.txt");
+    #[test]
+    fn test_input() {
+        assert_eq!(Day15::solve_part_1(TEST_INPUT).unwrap(), 1_320);
+        assert_eq!(Day15::solve_part_2(TEST_INPUT).unwrap(), 145);
+    }
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(
+            Day15::solve_part_1(include_str!("../inputs/day-15.txt")).unwrap(),
+            504_036
+        );
+    }
+
+    #[test]
+    fn test_part_2() {
+        assert_eq!(
+            Day15::solve_part_2(include_str!("../inputs/day-15.txt")).unwrap(),
+            295_719
+        );
+    }
+}
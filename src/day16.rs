@@ -0,0 +1,191 @@
+//! Day 16: The Floor Will Be Lava
+
+use crate::{
+    solution::Solution,
+    utils::{Direction, Grid, Pos},
+    Error, Result,
+};
+
+use std::{borrow::BorrowMut, str::FromStr};
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+    type Input = Contraption;
+    type Part1 = u16;
+    type Part2 = u16;
+
+    fn parse(input: &str) -> Result<Contraption> {
+        input.parse()
+    }
+
+    fn part_1(contraption: &Contraption) -> Result<u16> {
+        let mut contraption = contraption.clone();
+        Ok(contraption.count_energy((Direction::East, (0, 0))))
+    }
+
+    fn part_2(contraption: &Contraption) -> Result<u16> {
+        let mut contraption = contraption.clone();
+        let height = contraption.grid.height();
+        let width = contraption.grid.width();
+
+        (0..height)
+            .flat_map(|row| {
+                [
+                    (Direction::East, (row, 0)),
+                    (Direction::West, (row, width - 1)),
+                ]
+            })
+            .chain((0..width).flat_map(|col| {
+                [
+                    (Direction::South, (0, col)),
+                    (Direction::North, (height - 1, col)),
+                ]
+            }))
+            .map(|start| {
+                // reset the map
+                contraption.reset(&height, &width);
+                // count again for it
+                contraption.count_energy(start)
+            })
+            .max()
+            .ok_or_else(|| Error::Assumption(String::from("the contraption should not be empty")))
+    }
+}
+
+/// The grid of mirrors and splitters.
+#[derive(Clone)]
+pub struct Contraption {
+    grid: Grid<(Tile, u8)>,
+}
+
+impl Contraption {
+    fn reset(&mut self, height: &usize, width: &usize) {
+        for r in 0..*height {
+            for c in 0..*width {
+                self.grid[(r, c)].1 = 0;
+            }
+        }
+    }
+    fn count_energy(&mut self, start_beam: (Direction, Pos)) -> u16 {
+        let mut counter = 0;
+        let mut beams = vec![start_beam];
+
+        while let Some((current_direction, pos)) = beams.pop() {
+            let (tile, is_visited) = self.grid[pos].borrow_mut();
+            // one bit per direction a beam already crossed the tile in
+            let mask = 1 << current_direction as u8;
+            if *is_visited & mask != 0 {
+                continue;
+            }
+            if *is_visited == 0 {
+                counter += 1;
+            }
+
+            *is_visited |= mask;
+
+            let new_beams_directions = Self::find_new_directions(tile, current_direction);
+            for &new_direction in &new_beams_directions {
+                if let Some(next) = self.grid.towards(pos, new_direction) {
+                    beams.push((new_direction, next));
+                }
+            }
+        }
+        counter
+    }
+
+    fn find_new_directions(tile: &Tile, current_direction: Direction) -> Vec<Direction> {
+        let mut new_beams_directions = Vec::new();
+        match *tile {
+            Tile::Space => new_beams_directions.push(current_direction),
+            // `\` turns beams going east or west clockwise
+            Tile::MirrorUR => new_beams_directions.push(if current_direction.is_horizontal() {
+                current_direction.turn_right()
+            } else {
+                current_direction.turn_left()
+            }),
+
+            Tile::MirrorUL => new_beams_directions.push(if current_direction.is_horizontal() {
+                current_direction.turn_left()
+            } else {
+                current_direction.turn_right()
+            }),
+            Tile::SplitterVert => {
+                if current_direction.is_vertical() {
+                    new_beams_directions.push(current_direction);
+                } else {
+                    new_beams_directions.extend(&[Direction::North, Direction::South]);
+                }
+            }
+
+            Tile::SplitterHoriz => {
+                if current_direction.is_horizontal() {
+                    new_beams_directions.push(current_direction);
+                } else {
+                    new_beams_directions.extend(&[Direction::West, Direction::East]);
+                }
+            }
+        };
+        new_beams_directions
+    }
+}
+
+impl FromStr for Contraption {
+    type Err = Error;
+
+    fn from_str(input: &str) -> Result<Self> {
+        if input.is_empty() {
+            return Err(Error::at(input, input, "expected a contraption"));
+        }
+        let grid = Grid::parse(input, |c| {
+            let tile = match c {
+                '\\' => Tile::MirrorUR,
+                '/' => Tile::MirrorUL,
+                '|' => Tile::SplitterVert,
+                '-' => Tile::SplitterHoriz,
+                '.' => Tile::Space,
+                _ => return None,
+            };
+            // each tile carries the directions beams already crossed it in
+            Some((tile, 0))
+        })?;
+        Ok(Self { grid })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Tile {
+    MirrorUR,
+    MirrorUL,
+    SplitterVert,
+    SplitterHoriz,
+    Space,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    const TEST_INPUT: &str = include_str!("../inputs/day-16-test.txt");
+    #[test]
+    fn test_input() {
+        assert_eq!(Day16::solve_part_1(TEST_INPUT).unwrap(), 46);
+        assert_eq!(Day16::solve_part_2(TEST_INPUT).unwrap(), 51);
+    }
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(
+            Day16::solve_part_1(include_str!("../inputs/day-16.txt")).unwrap(),
+            7_034
+        );
+    }
+
+    #[test]
+    fn test_part_2() {
+        assert_eq!(
+            Day16::solve_part_2(include_str!("../inputs/day-16.txt")).unwrap(),
+            7_759
+        );
+    }
+}
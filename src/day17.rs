@@ -0,0 +1,149 @@
+//! Day 17: Clumsy Crucible
+
+use crate::{
+    solution::Solution,
+    utils::{search, Direction, Grid, Point},
+    Error, Result,
+};
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+    type Input = Grid<usize>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Grid<usize>> {
+        parse_grid(input)
+    }
+
+    fn part_1(grid: &Grid<usize>) -> Result<usize> {
+        let start = Point::new(0, 0);
+        let end = Point::new(grid.width() - 1, grid.height() - 1);
+        least_heat_loss(grid, &start, &end, get_neighbours::<1, 3>)
+            .ok_or_else(|| Error::Unsolvable(String::from("the crucible cannot reach the factory")))
+    }
+
+    fn part_2(grid: &Grid<usize>) -> Result<usize> {
+        let start = Point::new(0, 0);
+        let end = Point::new(grid.width() - 1, grid.height() - 1);
+        least_heat_loss(grid, &start, &end, get_neighbours::<4, 10>)
+            .ok_or_else(|| Error::Unsolvable(String::from("the crucible cannot reach the factory")))
+    }
+}
+
+fn get_neighbours<const MIN: usize, const MAX: usize>(
+    node: &Node,
+    grid: &Grid<usize>,
+) -> Vec<Node> {
+    let mut neighbors = Vec::new();
+    for (d, p) in valid_next(node.position, grid) {
+        if d == node.direction.opposite() {
+            // We can't go in the opposite direction.
+            continue;
+        } else if d != node.direction && node.direction_count >= MIN {
+            // We can only change direction if we've already gone in
+            // this direction 4 times or more.
+            neighbors.push(Node::new(d, p, 1));
+        } else if d == node.direction && node.direction_count < MAX {
+            // We can only go in the same direction if we haven't gone
+            // more than 10 times in this durection..
+            neighbors.push(Node::new(d, p, node.direction_count + 1));
+        }
+
+        // All other cases are invalid for p2.
+    }
+    neighbors
+}
+
+// The heat loss of every block, shared with `day17_lib`.
+pub(crate) fn parse_grid(input: &str) -> Result<Grid<usize>> {
+    if input.is_empty() {
+        return Err(Error::at(input, input, "expected a map"));
+    }
+    Grid::parse(input, |c| {
+        c.to_digit(10).map(|heat_loss| heat_loss as usize)
+    })
+}
+
+// The least heat lost from `start` to `end`, moving as `neighbor_fn` allows.
+fn least_heat_loss<F>(
+    grid: &Grid<usize>,
+    start: &Point<usize>,
+    end: &Point<usize>,
+    neighbor_fn: F,
+) -> Option<usize>
+where
+    F: Fn(&Node, &Grid<usize>) -> Vec<Node>,
+{
+    // We could be setting off either South or East at the start. Heat loss
+    // is a single digit, so a bucket queue beats a heap.
+    let starts = [Direction::South, Direction::East].map(|d| Node::new(d, *start, 0));
+    search::dial(
+        starts,
+        |node| {
+            neighbor_fn(node, grid).into_iter().map(|neighbor| {
+                let heat_loss = grid[neighbor.position];
+                (neighbor, heat_loss)
+            })
+        },
+        |node| node.position == *end,
+    )
+    .map(|found| found.cost)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+struct Node {
+    position: Point<usize>,
+    direction: Direction,
+    direction_count: usize,
+}
+
+impl Node {
+    fn new(direction: Direction, position: Point<usize>, direction_count: usize) -> Self {
+        Self {
+            position,
+            direction,
+            direction_count,
+        }
+    }
+}
+// The points next to `position` within the grid, shared with `day17_lib`.
+pub(crate) fn valid_next(
+    position: Point<usize>,
+    grid: &Grid<usize>,
+) -> Vec<(Direction, Point<usize>)> {
+    Direction::ALL
+        .into_iter()
+        .filter_map(|d| Some((d, grid.towards(position.into(), d)?.into())))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    const TEST_INPUT: &str = include_str!("../inputs/day-17-test.txt");
+
+    #[test]
+    fn test_input() {
+        assert_eq!(Day17::solve_part_1(TEST_INPUT).unwrap(), 102);
+        assert_eq!(Day17::solve_part_2(TEST_INPUT).unwrap(), 94);
+    }
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(
+            Day17::solve_part_1(include_str!("../inputs/day-17.txt")).unwrap(),
+            902
+        );
+    }
+
+    #[test]
+    fn test_part_2() {
+        assert_eq!(
+            Day17::solve_part_2(include_str!("../inputs/day-17.txt")).unwrap(),
+            1_073
+        );
+    }
+}
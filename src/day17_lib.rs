@@ -0,0 +1,129 @@
+//! Day 17: Clumsy Crucible, with A*
+
+use crate::{
+    day17::{parse_grid, valid_next},
+    err,
+    solution::Solution,
+    utils::{search, Direction, Grid, Point},
+    Result,
+};
+
+// Same puzzle as day 17, solved with A* guided by the distance left to the
+// factory, every step losing at least 1 heat.
+pub struct Day17Lib;
+
+impl Solution for Day17Lib {
+    const DAY: u8 = 17;
+    type Input = Grid<usize>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Grid<usize>> {
+        parse_grid(input)
+    }
+
+    fn part_1(graph: &Grid<usize>) -> Result<usize> {
+        let start = Node {
+            position: Point::new(0, 0),
+            direction: Direction::East,
+            direction_count: 1,
+        };
+        let end = Point::new(graph.width() - 1, graph.height() - 1);
+
+        let Some(found) = search::astar(
+            [start],
+            |node| successors(node, graph, 1, 3),
+            |node| node.position.manhattan(end),
+            |node| node.position == end,
+        ) else {
+            return err!(Unsolvable, "the crucible cannot reach the factory");
+        };
+
+        Ok(found.cost)
+    }
+
+    fn part_2(graph: &Grid<usize>) -> Result<usize> {
+        let start = Node {
+            position: Point::new(0, 0),
+            direction: Direction::East,
+            direction_count: 1,
+        };
+        let end = Point::new(graph.width() - 1, graph.height() - 1);
+
+        let Some(found) = search::astar(
+            [start],
+            |node| successors(node, graph, 4, 10),
+            |node| node.position.manhattan(end),
+            |node| node.position == end,
+        ) else {
+            return err!(Unsolvable, "the crucible cannot reach the factory");
+        };
+
+        Ok(found.cost)
+    }
+}
+
+fn successors(node: &Node, graph: &Grid<usize>, min: usize, max: usize) -> Vec<(Node, usize)> {
+    let mut neighbours = Vec::<(Node, usize)>::new();
+    for (direction, position) in valid_next(node.position, graph) {
+        if direction == node.direction.opposite() {
+            continue;
+        }
+
+        if direction != node.direction && node.direction_count >= min {
+            neighbours.push((
+                Node {
+                    position,
+                    direction,
+                    direction_count: 1,
+                },
+                graph[position],
+            ));
+        } else if direction == node.direction && node.direction_count < max {
+            neighbours.push((
+                Node {
+                    position,
+                    direction,
+                    direction_count: node.direction_count + 1,
+                },
+                graph[position],
+            ));
+        }
+    }
+    neighbours
+}
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+struct Node {
+    position: Point<usize>,
+    direction: Direction,
+    direction_count: usize,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    const TEST_INPUT: &str = include_str!("../inputs/day-17-test.txt");
+
+    #[test]
+    fn test_input() {
+        assert_eq!(Day17Lib::solve_part_1(TEST_INPUT).unwrap(), 102);
+        assert_eq!(Day17Lib::solve_part_2(TEST_INPUT).unwrap(), 94);
+    }
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(
+            Day17Lib::solve_part_1(include_str!("../inputs/day-17.txt")).unwrap(),
+            902
+        );
+    }
+
+    #[test]
+    fn test_part_2() {
+        assert_eq!(
+            Day17Lib::solve_part_2(include_str!("../inputs/day-17.txt")).unwrap(),
+            1_073
+        );
+    }
+}
@@ -0,0 +1,103 @@
+//! Day 18: Lavaduct Lagoon
+
+use crate::{
+    error,
+    solution::Solution,
+    utils::{Direction, Point, Polygon},
+    Error, Result,
+};
+
+pub type Step = (Direction, isize);
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: u8 = 18;
+    // each line is read twice: once as written and once from its hex colour
+    type Input = Vec<(Step, Step)>;
+    type Part1 = isize;
+    type Part2 = isize;
+
+    fn parse(input: &str) -> Result<Vec<(Step, Step)>> {
+        input.lines().map(|line| parse_line(input, line)).collect()
+    }
+
+    fn part_1(dig_plans: &Vec<(Step, Step)>) -> Result<isize> {
+        lagoon_volume(dig_plans.iter().map(|&(step, _)| step))
+    }
+
+    fn part_2(dig_plans: &Vec<(Step, Step)>) -> Result<isize> {
+        lagoon_volume(dig_plans.iter().map(|&(_, step)| step))
+    }
+}
+
+// The trench is one metre wide around the lagoon: count the points inside
+// the path it follows as well as the ones on it.
+fn lagoon_volume(dig_plans: impl Iterator<Item = Step>) -> Result<isize> {
+    let steps = dig_plans.map(|(direction, distance)| (direction, distance as i64));
+    let lagoon = Polygon::from_steps(Point::new(0, 0), steps);
+    Ok((lagoon.interior() + lagoon.boundary()) as isize)
+}
+
+fn parse_line(input: &str, line: &str) -> Result<(Step, Step)> {
+    let mut tokens = line.split_ascii_whitespace();
+    let mut next = |what: &str| {
+        tokens
+            .next()
+            .ok_or_else(|| Error::at(input, &line[line.len()..], format!("expected {what}")))
+    };
+    let direction = next("a direction")?;
+    let offset = error::parse::<isize>(input, next("a distance")?)?;
+    let colour = next("a colour")?;
+    let direction = match direction.chars().collect::<Vec<_>>()[..] {
+        [c @ ('U' | 'D' | 'L' | 'R')] => Direction::from_char(c),
+        _ => None,
+    }
+    .ok_or_else(|| error::unexpected(input, direction))?;
+    let hex_data = colour
+        .trim_start_matches('(')
+        .trim_end_matches(')')
+        .trim_start_matches('#');
+    // the first five digits are the distance, the last one the direction
+    let (Some(distance), Some(hex_direction)) = (
+        hex_data
+            .get(..5)
+            .and_then(|distance| isize::from_str_radix(distance, 16).ok()),
+        hex_data
+            .chars()
+            .nth(5)
+            .filter(|c| hex_data.len() == 6 && c.is_ascii_digit())
+            .and_then(Direction::from_char),
+    ) else {
+        return Err(Error::at(input, colour, "expected a colour like (#70c710)"));
+    };
+
+    Ok(((direction, offset), (hex_direction, distance)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    const TEST_INPUT: &str = include_str!("../inputs/day-18-test.txt");
+    #[test]
+    fn test_input() {
+        assert_eq!(Day18::solve_part_1(TEST_INPUT).unwrap(), 62);
+        assert_eq!(Day18::solve_part_2(TEST_INPUT).unwrap(), 952_408_144_115);
+    }
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(
+            Day18::solve_part_1(include_str!("../inputs/day-18.txt")).unwrap(),
+            52_035
+        );
+    }
+
+    #[test]
+    fn test_part_2() {
+        assert_eq!(
+            Day18::solve_part_2(include_str!("../inputs/day-18.txt")).unwrap(),
+            60_612_092_439_765
+        );
+    }
+}
@@ -0,0 +1,249 @@
+//! Day 19: Aplenty
+
+use crate::{
+    err, error,
+    solution::Solution,
+    utils::{Interval, IntervalSet},
+    Error, Result,
+};
+
+use std::{collections::HashMap, str::FromStr};
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const DAY: u8 = 19;
+    type Input = WorkFlows;
+    type Part1 = i32;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<WorkFlows> {
+        input.parse()
+    }
+
+    fn part_1(data: &WorkFlows) -> Result<i32> {
+        let mut ans = 0;
+        for system in data.system.iter() {
+            let mut start_workflow = "in";
+            let mut index = 0;
+            loop {
+                // know for sure it must be inside the map
+                let rules = &data.rules[start_workflow];
+
+                // only take the first rule
+                match &rules[index] {
+                    Rule::Accept => {
+                        ans += system.values().sum::<i32>();
+                        break;
+                    }
+                    Rule::Reject => break,
+                    Rule::WorkFlow(workflow) => {
+                        start_workflow = workflow;
+                        index = 0;
+                    }
+                    Rule::Condition((s, comparison, value, result)) => {
+                        let comparison_result = match comparison {
+                            '>' => system[s] > *value,
+                            '<' => system[s] < *value,
+                            _ => return err!(Assumption, "unknown comparison {comparison}"),
+                        };
+                        if comparison_result {
+                            if result == "A" {
+                                ans += system.values().sum::<i32>();
+                                break;
+                            } else if result == "R" {
+                                break;
+                            } else {
+                                start_workflow = result;
+                                index = 0;
+                            }
+                        } else {
+                            index += 1;
+                        }
+                    }
+                }
+            }
+        }
+        Ok(ans)
+    }
+
+    fn part_2(data: &WorkFlows) -> Result<usize> {
+        count_accepted(
+            &data.rules,
+            "in",
+            std::array::from_fn(|_| IntervalSet::from(Interval::new(1, 4_001))),
+        )
+    }
+}
+
+//https://github.com/AxlLind/AdventOfCode2023/blob/main/src/bin/19.rs
+fn count_accepted(
+    workflows: &HashMap<String, Vec<Rule>>,
+    curr: &str,
+    mut ranges: [IntervalSet; 4],
+) -> Result<usize> {
+    if curr == "A" {
+        return Ok(ranges.iter().map(|r| r.len() as usize).product());
+    }
+    if curr == "R" {
+        return Ok(0);
+    }
+    let mut ans = 0;
+
+    for rule in &workflows[curr] {
+        let (p, op, n, label) = match rule {
+            Rule::Accept => return Ok(ans + count_accepted(workflows, "A", ranges)?),
+            Rule::Reject => return Ok(ans),
+            Rule::WorkFlow(label) => return Ok(ans + count_accepted(workflows, label, ranges)?),
+            Rule::Condition((p, op, n, label)) => (*p, *op, i64::from(*n), label),
+        };
+        let Some(i) = "xmas".chars().position(|c| c == p) else {
+            return err!(Assumption, "unknown category {p}");
+        };
+        let matching = IntervalSet::from(if op == '<' {
+            Interval::new(i64::MIN, n)
+        } else {
+            Interval::new(n + 1, i64::MAX)
+        });
+        let mut newranges = ranges.clone();
+        newranges[i] = ranges[i].intersect(&matching);
+        ans += count_accepted(workflows, label, newranges)?;
+        ranges[i] = ranges[i].difference(&matching);
+    }
+    Ok(ans)
+}
+
+impl FromStr for WorkFlows {
+    type Err = Error;
+
+    fn from_str(input: &str) -> Result<Self> {
+        let (left, right) = error::split_once(input, input, "\n\n")?;
+
+        // workflows sent to, checked once they are all known
+        let mut targets = Vec::new();
+        let rules = left
+            .lines()
+            .map(|line| {
+                let (workflow, rules) = error::split_once(input, line, "{")?;
+                Ok((
+                    String::from(workflow),
+                    rules
+                        .trim_end_matches('}')
+                        .split(',')
+                        .map(|rule| match rule.split_once(':') {
+                            Some((condition, target)) => {
+                                let category = &condition[..condition.len().min(1)];
+                                let op = condition.get(1..2).unwrap_or_default();
+                                if !matches!(category, "x" | "m" | "a" | "s") {
+                                    return Err(error::unexpected(input, category));
+                                }
+                                if !matches!(op, "<" | ">") {
+                                    return Err(error::unexpected(input, op));
+                                }
+                                targets.push(target);
+                                Ok(Rule::Condition((
+                                    category.chars().next().unwrap_or_default(),
+                                    op.chars().next().unwrap_or_default(),
+                                    error::parse(input, &condition[2..])?,
+                                    String::from(target),
+                                )))
+                            }
+                            None => Ok(match rule {
+                                "A" => Rule::Accept,
+                                "R" => Rule::Reject,
+                                _ => {
+                                    targets.push(rule);
+                                    Rule::WorkFlow(String::from(rule))
+                                }
+                            }),
+                        })
+                        .collect::<Result<Vec<_>>>()?,
+                ))
+            })
+            .collect::<Result<HashMap<String, Vec<Rule>>>>()?;
+        if let Some(target) = targets
+            .into_iter()
+            .find(|&target| !matches!(target, "A" | "R") && !rules.contains_key(target))
+        {
+            return Err(Error::at(
+                input,
+                target,
+                format!("unknown workflow {target}"),
+            ));
+        }
+        if !rules.contains_key("in") {
+            return err!(Assumption, "there should be an `in` workflow");
+        }
+
+        let system = right
+            .lines()
+            .map(|line| {
+                let ratings = line
+                    .trim_end_matches('}')
+                    .trim_start_matches('{')
+                    .split(',')
+                    .map(|p| {
+                        let (name, value) = error::split_once(input, p, "=")?;
+                        match name {
+                            "x" | "m" | "a" | "s" => Ok((
+                                name.chars().next().unwrap_or_default(),
+                                error::parse(input, value)?,
+                            )),
+                            _ => Err(error::unexpected(input, name)),
+                        }
+                    })
+                    .collect::<Result<HashMap<char, i32>>>()?;
+                match ratings.len() {
+                    4 => Ok(ratings),
+                    _ => Err(Error::at(input, line, "expected x, m, a and s ratings")),
+                }
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self { rules, system })
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum Rule {
+    Accept,
+    Reject,
+    WorkFlow(String),
+    Condition((char, char, i32, String)),
+}
+
+/// The workflows by name and the parts to sort.
+#[derive(Debug)]
+pub struct WorkFlows {
+    rules: HashMap<String, Vec<Rule>>,
+    system: Vec<HashMap<char, i32>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    const TEST_INPUT: &str = include_str!("../inputs/day-19-test.txt");
+    #[test]
+    fn test_input() {
+        assert_eq!(Day19::solve_part_1(TEST_INPUT).unwrap(), 19_114);
+        assert_eq!(
+            Day19::solve_part_2(TEST_INPUT).unwrap(),
+            167_409_079_868_000
+        );
+    }
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(
+            Day19::solve_part_1(include_str!("../inputs/day-19.txt")).unwrap(),
+            395_382
+        );
+    }
+
+    #[test]
+    fn test_part_2() {
+        assert_eq!(
+            Day19::solve_part_2(include_str!("../inputs/day-19.txt")).unwrap(),
+            103_557_657_654_583
+        );
+    }
+}
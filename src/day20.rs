@@ -0,0 +1,301 @@
+//! Day 20: Pulse Propagation
+
+use crate::{err, error, solution::Solution, utils::first_alignment, Error, Result};
+
+use std::{
+    collections::{HashMap, VecDeque},
+    str::FromStr,
+};
+
+pub struct Day20;
+
+// How many times part 2 presses the button before giving up on the cycles.
+const MAX_PRESSES: usize = 100_000;
+
+impl Solution for Day20 {
+    const DAY: u8 = 20;
+    type Input = Configuration;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Configuration> {
+        input.parse()
+    }
+
+    fn part_1(configuration: &Configuration) -> Result<usize> {
+        let mut configuration = configuration.clone();
+        let mut ans = [0; 2];
+        for _ in 0..1_000 {
+            // with no predecessors to watch, every press is counted
+            if let Press::Counts(low, high) = configuration.generate_pulse(&[]) {
+                ans[0] += low;
+                ans[1] += high;
+            }
+        }
+        Ok(ans.iter().product())
+    }
+
+    fn part_2(configuration: &Configuration) -> Result<usize> {
+        let mut configuration = configuration.clone();
+        // rx is flipflop and it has 1 predecessor - bq which is an invertor
+        // therefore, if rx wants to receive a LOW -> invertor inputs must all be
+        // HIGH
+        // SOLUTION:
+        // all of predecessor of rx's predecessor must give a HIGH inputs
+
+        let source = configuration
+            .modules
+            .iter()
+            .find(|m| m.1.outputs.contains(&String::from("rx")))
+            .ok_or_else(|| Error::Assumption(String::from("a module should lead to rx")))?
+            .0
+            .clone();
+        let mut predecessors = Vec::new();
+        for (name, Module { outputs, .. }) in configuration.modules.iter() {
+            if outputs.contains(&source) {
+                predecessors.push(name.clone());
+            }
+        }
+
+        // the presses at which each predecessor first sent a HIGH, three
+        // times to know when its cycle starts and to check how long it is
+        let mut presses = vec![Vec::new(); predecessors.len()];
+        let mut count = 0;
+        while presses.iter().any(|p| p.len() < 3) {
+            if count == MAX_PRESSES {
+                return err!(
+                    Unsolvable,
+                    "some module before {source} sent fewer than 3 HIGH pulses in {MAX_PRESSES} presses"
+                );
+            }
+            count += 1;
+            match configuration.generate_pulse(&predecessors) {
+                Press::Rx => return Ok(count),
+                Press::Fired(fired) => {
+                    for c in fired {
+                        presses[c].push(count);
+                    }
+                }
+                Press::Counts(..) => {}
+            }
+        }
+        let mut cycles = Vec::new();
+        for (name, p) in predecessors.iter().zip(&presses) {
+            let period = p[1] - p[0];
+            if p[2] - p[1] != period {
+                return err!(
+                    Unsolvable,
+                    "{name} sent HIGH pulses at presses {}, {} and {}, not every {period}",
+                    p[0],
+                    p[1],
+                    p[2]
+                );
+            }
+            cycles.push((p[0], period));
+        }
+        first_alignment(&cycles)
+            .ok_or_else(|| Error::Unsolvable(String::from("the HIGH pulses never line up")))
+    }
+}
+
+/// The modules and where they send their pulses.
+#[derive(Debug, Clone)]
+pub struct Configuration {
+    modules: HashMap<String, Module>,
+}
+
+// What happened during one press of the button.
+#[derive(Debug, PartialEq, Eq)]
+enum Press {
+    /// rx received a LOW pulse.
+    Rx,
+    /// The predecessors, by index, that sent a HIGH pulse.
+    Fired(Vec<usize>),
+    /// The LOW and HIGH pulses sent, when none of the above happened.
+    Counts(usize, usize),
+}
+
+impl Configuration {
+    // Press the button once, watching rx and `predecessors` if there are any.
+    fn generate_pulse(&mut self, predecessors: &[String]) -> Press {
+        // start pressing the button with low signal
+        let mut queue =
+            VecDeque::from([(String::from("button"), String::from("broadcaster"), false)]);
+        let mut count = [0; 2];
+        // the predecessors which gave a high input in this simulation
+        let mut fired = Vec::new();
+        while let Some((sender, receiver, pulse)) = queue.pop_front() {
+            if !predecessors.is_empty() && receiver == "rx" && !pulse {
+                return Press::Rx;
+            }
+            count[pulse as usize] += 1;
+            if let Some(Module {
+                module_type,
+                outputs,
+            }) = self.modules.get_mut(&receiver)
+            {
+                match module_type {
+                    ModuleType::FlipFlop(flipflop) => {
+                        if !pulse {
+                            *flipflop = !*flipflop;
+                            for o in outputs {
+                                queue.push_back((receiver.clone(), o.clone(), *flipflop));
+                            }
+                        }
+                    }
+                    ModuleType::Conjunction(conjunction) => {
+                        conjunction.insert(sender.clone(), pulse);
+                        let out = !conjunction.values().all(|v| *v);
+                        if out {
+                            if let Some(predecessor) =
+                                predecessors.iter().position(|p| *p == receiver)
+                            {
+                                if !fired.contains(&predecessor) {
+                                    fired.push(predecessor);
+                                }
+                            }
+                        }
+
+                        for o in outputs {
+                            queue.push_back((receiver.clone(), o.clone(), out));
+                        }
+                    }
+                    ModuleType::Broadcaster => {
+                        for o in outputs {
+                            queue.push_back((receiver.clone(), o.clone(), false));
+                        }
+                    }
+                }
+            }
+        }
+        if fired.is_empty() {
+            Press::Counts(count[0], count[1])
+        } else {
+            Press::Fired(fired)
+        }
+    }
+}
+
+impl FromStr for Configuration {
+    type Err = Error;
+
+    fn from_str(input: &str) -> Result<Configuration> {
+        let mut conjunctions = HashMap::<String, HashMap<String, bool>>::new();
+        let mut modules = HashMap::new();
+        for line in input.lines() {
+            let (lhs, rhs) = error::split_once(input, line, " -> ")?;
+            let rhs = rhs.split(", ").map(|s| s.to_string()).collect();
+
+            if let Some(name) = lhs.strip_prefix('%') {
+                modules.insert(
+                    name.to_string(),
+                    Module {
+                        module_type: ModuleType::FlipFlop(false),
+                        outputs: rhs,
+                    },
+                );
+            } else if let Some(name) = lhs.strip_prefix('&') {
+                conjunctions.insert(name.to_string(), HashMap::new());
+                modules.insert(
+                    name.to_string(),
+                    Module {
+                        module_type: ModuleType::Conjunction(HashMap::new()),
+                        outputs: rhs,
+                    },
+                );
+            } else if lhs == "broadcaster" {
+                modules.insert(
+                    lhs.to_string(),
+                    Module {
+                        module_type: ModuleType::Broadcaster,
+                        outputs: rhs,
+                    },
+                );
+            } else {
+                return Err(error::unexpected(input, lhs));
+            }
+        }
+
+        for (name, module) in &modules {
+            for out in &module.outputs {
+                if let Some(conj) = conjunctions.get_mut(out) {
+                    conj.insert(name.clone(), false);
+                }
+            }
+        }
+
+        for (name, module) in modules.iter_mut() {
+            if let Some(conj) = conjunctions.get(name) {
+                module.module_type = ModuleType::Conjunction(conj.clone());
+            }
+        }
+
+        Ok(Self { modules })
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Module {
+    module_type: ModuleType,
+    outputs: Vec<String>,
+}
+
+#[derive(Debug, Clone)]
+enum ModuleType {
+    FlipFlop(bool),
+    Conjunction(HashMap<String, bool>),
+    Broadcaster,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    const TEST_INPUT_1: &str = include_str!("../inputs/day-20-test-1.txt");
+    const TEST_INPUT_2: &str = include_str!("../inputs/day-20-test-2.txt");
+
+    #[test]
+    fn test_input() {
+        assert_eq!(Day20::solve_part_1(TEST_INPUT_1).unwrap(), 32_000_000);
+        assert_eq!(Day20::solve_part_1(TEST_INPUT_2).unwrap(), 11_687_500);
+    }
+
+    #[test]
+    fn test_fired_together() {
+        // the second press turns a off, and both inverters send HIGH
+        let input = "\
+broadcaster -> a
+%a -> inva, invb
+&inva -> hub
+&invb -> hub
+&hub -> out";
+        let mut configuration = Day20::parse(input).unwrap();
+        let predecessors = [String::from("inva"), String::from("invb")];
+        assert!(matches!(
+            configuration.generate_pulse(&predecessors),
+            Press::Counts(..)
+        ));
+        assert_eq!(
+            configuration.generate_pulse(&predecessors),
+            Press::Fired(vec![0, 1])
+        );
+        // with both HIGH, hub sends rx its LOW on that same press
+        let input = input.replace("out", "rx");
+        assert_eq!(Day20::solve_part_2(&input).unwrap(), 2);
+    }
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(
+            Day20::solve_part_1(include_str!("../inputs/day-20.txt")).unwrap(),
+            681_194_780
+        );
+    }
+
+    #[test]
+    fn test_part_2() {
+        assert_eq!(
+            Day20::solve_part_2(include_str!("../inputs/day-20.txt")).unwrap(),
+            238_593_356_738_827
+        );
+    }
+}
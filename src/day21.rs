@@ -0,0 +1,186 @@
+//! Day 21: Step Counter
+
+use crate::{
+    err,
+    solution::Solution,
+    utils::{poly, search, Grid},
+    Error, Result,
+};
+
+use std::collections::HashMap;
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    const DAY: u8 = 21;
+    type Input = Garden;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Garden> {
+        parse(input)
+    }
+
+    fn part_1(garden: &Garden) -> Result<usize> {
+        part_1(garden)
+    }
+
+    fn part_2(garden: &Garden) -> Result<usize> {
+        part_2(garden)
+    }
+}
+
+pub type Garden = (Grid<Tile>, Coord);
+//https://nickymeuleman.netlify.app/garden/aoc2023-day21
+/// A position in the garden, which repeats forever in part two.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Coord {
+    col: i64,
+    row: i64,
+}
+
+impl Coord {
+    fn infinite_neighbours(&self) -> Vec<Self> {
+        vec![
+            Coord {
+                col: self.col,
+                row: self.row - 1,
+            },
+            Coord {
+                col: self.col,
+                row: self.row + 1,
+            },
+            Coord {
+                col: self.col - 1,
+                row: self.row,
+            },
+            Coord {
+                col: self.col + 1,
+                row: self.row,
+            },
+        ]
+    }
+}
+
+/// A tile of the garden.
+#[derive(Debug, PartialEq)]
+pub enum Tile {
+    Garden,
+    Rock,
+}
+
+fn parse(input: &str) -> Result<Garden> {
+    let chars = Grid::parse(input, |c| matches!(c, '.' | '#' | 'S').then_some(c))?;
+    let Some((row, col)) = chars.position(|&c| c == 'S') else {
+        return err!(Assumption, "the garden should have a start");
+    };
+    let grid = chars.map(|&c| match c {
+        '#' => Tile::Rock,
+        _ => Tile::Garden,
+    });
+    let start = Coord {
+        col: col as i64,
+        row: row as i64,
+    };
+    Ok((grid, start))
+}
+
+fn part_1((grid, start): &Garden) -> Result<usize> {
+    let start = (start.row as usize, start.col as usize);
+    let steps = search::reachable(
+        start,
+        |&pos| {
+            grid.neighbours_4(pos)
+                .filter(|&pos| grid[pos] == Tile::Garden)
+                .collect::<Vec<_>>()
+        },
+        64,
+    );
+    Ok(ending_after(&steps, 64))
+}
+
+// Let f(n) be the number of spaces you can reach after n steps. Let X be the length of your input grid. f(n), f(n+X), f(n+2X), ...., is a quadratic
+// You can find it by finding the first 3 values, then use that to interpolate the final answer.
+fn part_2((grid, start): &Garden) -> Result<usize> {
+    let goal: usize = 26_501_365;
+    let size = grid.height();
+    if grid.width() != size {
+        return err!(Assumption, "the garden should be square");
+    }
+    // the amount of steps it takes to reach an edge of the map (all tiles in the same row and column as start are gardens)
+    let to_edge = size / 2;
+    if (start.row, start.col) != (to_edge as i64, to_edge as i64) {
+        return err!(
+            Assumption,
+            "the start should be in the middle of the garden"
+        );
+    }
+    if !(goal - to_edge).is_multiple_of(size) {
+        return err!(
+            Assumption,
+            "{goal} steps should reach an edge of a copy of the garden, {size} wide"
+        );
+    }
+    let samples = [0, 1, 2].map(|i| to_edge + size * i);
+    let steps = search::reachable(
+        start.clone(),
+        |pos| {
+            pos.infinite_neighbours()
+                .into_iter()
+                .filter(|pos| *grid.get_wrapping(pos.row, pos.col) == Tile::Garden)
+                .collect::<Vec<_>>()
+        },
+        samples[2],
+    );
+
+    // the quadratic through the 3 samples, in steps
+    let samples = samples
+        .map(|n| (n as i64, ending_after(&steps, n) as i64))
+        .to_vec();
+    let reached = poly::evaluate(&poly::lagrange(&samples), goal as i64);
+    reached
+        .to_integer()
+        .and_then(|reached| usize::try_from(reached).ok())
+        .ok_or_else(|| Error::Unsolvable(format!("reached a fractional {reached} plots")))
+}
+
+// How many plots can be ended on after exactly `n` steps, given the fewest
+// steps to each: any reached in fewer steps of the same parity, by stepping
+// back and forth.
+fn ending_after<N>(steps: &HashMap<N, usize>, n: usize) -> usize {
+    steps
+        .values()
+        .filter(|&&m| m <= n && m % 2 == n % 2)
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    const TEST_INPUT: &str = include_str!("../inputs/day-21-test.txt");
+    #[test]
+    fn test_input() {
+        assert_eq!(Day21::solve_part_1(TEST_INPUT).unwrap(), 42);
+        // 26501365 steps do not end on an edge of the example's copies
+        assert!(matches!(
+            Day21::solve_part_2(TEST_INPUT),
+            Err(Error::Assumption(_))
+        ));
+    }
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(
+            Day21::solve_part_1(include_str!("../inputs/day-21.txt")).unwrap(),
+            3_503
+        );
+    }
+
+    #[test]
+    fn test_part_2() {
+        assert_eq!(
+            Day21::solve_part_2(include_str!("../inputs/day-21.txt")).unwrap(),
+            584_211_423_220_706
+        );
+    }
+}
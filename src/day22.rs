@@ -0,0 +1,188 @@
+//! Day 22: Sand Slabs
+
+use crate::{error, input, solution::Solution, Error, Result};
+use itertools::Itertools;
+
+use std::collections::{HashMap, HashSet};
+pub type Bricks = Vec<(usize, usize, usize, usize, usize, usize, usize)>;
+type Grid = HashMap<(usize, usize, usize), usize>;
+
+pub struct Day22;
+
+//https://github.com/AxlLind/AdventOfCode2023/blob/main/src/bin/22.rs
+impl Solution for Day22 {
+    const DAY: u8 = 22;
+    // bricks after they have all settled, plus who supports whom
+    type Input = (Bricks, Supports);
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<(Bricks, Supports)> {
+        let (bricks, grid) = generate_bricks(input)?;
+        let supports = find_supports(&bricks, &grid);
+        Ok((bricks, supports))
+    }
+
+    fn part_1((bricks, (above, below)): &(Bricks, Supports)) -> Result<usize> {
+        let mut ans = 0;
+        for b in 0..bricks.len() {
+            let mut falling = HashSet::new();
+            if_disintegrated(&mut falling, above, below, b);
+            ans += if falling.len() == 1 { 1 } else { 0 };
+        }
+        Ok(ans)
+    }
+
+    fn part_2((bricks, (above, below)): &(Bricks, Supports)) -> Result<usize> {
+        let mut ans = 0;
+        for b in 0..bricks.len() {
+            let mut falling = HashSet::new();
+            if_disintegrated(&mut falling, above, below, b);
+            ans += falling.len() - 1;
+        }
+        Ok(ans)
+    }
+}
+
+pub type Supports = (
+    HashMap<usize, HashSet<usize>>,
+    HashMap<usize, HashSet<usize>>,
+);
+
+fn find_supports(bricks: &Bricks, grid: &Grid) -> Supports {
+    let mut above = HashMap::<_, HashSet<_>>::new();
+    let mut below = HashMap::<_, HashSet<_>>::new();
+    for &(x1, y1, z1, x2, y2, _, i) in bricks {
+        for (x, y) in (x1..=x2).cartesian_product(y1..=y2) {
+            if let Some(&j) = grid.get(&(x, y, z1 - 1)) {
+                above.entry(j).or_default().insert(i);
+                below.entry(i).or_default().insert(j);
+            }
+        }
+    }
+    (above, below)
+}
+
+fn parse(input: &str) -> Result<Bricks> {
+    input::lines(input)
+        .enumerate()
+        .map(|(i, l)| {
+            let (a, b) = error::split_once(input, l, "~")?;
+            let (x1, y1, z1) = parse_corner(input, a)?;
+            let (x2, y2, z2) = parse_corner(input, b)?;
+            if x1 > x2 || y1 > y2 || z1 > z2 || z1 == 0 {
+                return Err(Error::at(
+                    input,
+                    l,
+                    "expected the lower end first, above the ground",
+                ));
+            }
+            Ok((x1, y1, z1, x2, y2, z2, i))
+        })
+        .collect()
+}
+
+fn parse_corner(input: &str, s: &str) -> Result<(usize, usize, usize)> {
+    s.split(',')
+        .map(|w| error::parse::<usize>(input, w))
+        .collect::<Result<Vec<_>>>()?
+        .into_iter()
+        .collect_tuple()
+        .ok_or_else(|| Error::at(input, s, "expected x,y,z"))
+}
+
+fn generate_bricks(input: &str) -> Result<(Bricks, Grid)> {
+    let mut bricks = parse(input)?;
+    let mut grid: HashMap<(usize, usize, usize), usize> = HashMap::new();
+    for &(x1, y1, z1, x2, y2, z2, i) in &bricks {
+        for x in x1..=x2 {
+            for y in y1..=y2 {
+                for z in z1..=z2 {
+                    grid.insert((x, y, z), i);
+                }
+            }
+        }
+    }
+    loop {
+        let mut done = true;
+        for b in &mut bricks {
+            let mut can_fall = true;
+            while can_fall {
+                let (x1, y1, z1, x2, y2, z2, i) = *b;
+                if z1 == 1 {
+                    break;
+                }
+                for (x, y) in (x1..=x2).cartesian_product(y1..=y2) {
+                    if grid.contains_key(&(x, y, z1 - 1)) {
+                        can_fall = false;
+                        break;
+                    }
+                }
+                if can_fall {
+                    *b = (x1, y1, z1 - 1, x2, y2, z2 - 1, i);
+                    for (x, y) in (x1..=x2).cartesian_product(y1..=y2) {
+                        grid.remove(&(x, y, z2));
+                        grid.insert((x, y, z1 - 1), i);
+                    }
+                    done = false;
+                }
+            }
+        }
+        if done {
+            break;
+        }
+    }
+    Ok((bricks, grid))
+}
+
+fn if_disintegrated(
+    falling: &mut HashSet<usize>,
+    above: &HashMap<usize, HashSet<usize>>,
+    below: &HashMap<usize, HashSet<usize>>,
+    brick: usize,
+) {
+    if !falling.insert(brick) {
+        return;
+    }
+    let Some(parents) = above.get(&brick) else {
+        return;
+    };
+    for &parent in parents {
+        if below[&parent].iter().all(|x| falling.contains(x)) {
+            if_disintegrated(falling, above, below, parent);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    const TEST_INPUT: &str = include_str!("../inputs/day-22-test.txt");
+    #[test]
+    fn test_input() {
+        assert_eq!(Day22::solve_part_1(TEST_INPUT).unwrap(), 5);
+        assert_eq!(Day22::solve_part_2(TEST_INPUT).unwrap(), 7);
+    }
+
+    #[test]
+    fn test_windows_input() {
+        let input = TEST_INPUT.replace('\n', "\r\n") + "\r\n";
+        assert_eq!(Day22::solve_part_1(&input).unwrap(), 5);
+    }
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(
+            Day22::solve_part_1(include_str!("../inputs/day-22.txt")).unwrap(),
+            389
+        );
+    }
+
+    #[test]
+    fn test_part_2() {
+        assert_eq!(
+            Day22::solve_part_2(include_str!("../inputs/day-22.txt")).unwrap(),
+            70_609
+        );
+    }
+}
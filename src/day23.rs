@@ -0,0 +1,103 @@
+//! Day 23: A Long Walk
+
+use crate::{
+    err,
+    solution::Solution,
+    utils::{graph::Graph, Direction, Grid},
+    Error, Result,
+};
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    const DAY: u8 = 23;
+    type Input = Grid<u8>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Grid<u8>> {
+        let grid = Grid::parse(input, |c| "#.^>v<".contains(c).then_some(c as u8))?;
+        // every walk starts from the second tile of the first row
+        if grid.get((0, 1)) != Some(&b'.') {
+            return err!(Assumption, "the path should start at the second tile");
+        }
+        Ok(grid)
+    }
+
+    fn part_1(grid: &Grid<u8>) -> Result<usize> {
+        longest_walk(grid, true)
+    }
+
+    fn part_2(grid: &Grid<u8>) -> Result<usize> {
+        longest_walk(grid, false)
+    }
+}
+
+// The length of the longest walk from the start to the end that never steps
+// on a tile twice, going down the slopes only if `slippery`.
+fn longest_walk(grid: &Grid<u8>, slippery: bool) -> Result<usize> {
+    let (start, end) = ((0, 1), (grid.height() - 1, grid.width() - 2));
+    let graph = Graph::compress(grid, &[start, end], |from, direction, to| {
+        grid[to] != b'#'
+            && match grid[from] {
+                b'.' => true,
+                b'#' => false,
+                slope => !slippery || Direction::from_char(slope as char) == Some(direction),
+            }
+    });
+    // both are kept as nodes, unless the grid is too small to hold them
+    let (Some(start), Some(end)) = (graph.node(start), graph.node(end)) else {
+        return err!(
+            Assumption,
+            "the path should go from the second tile to the second to last"
+        );
+    };
+    let mut seen = vec![false; graph.len()];
+    longest_from(&graph, start, end, &mut seen)
+        .ok_or_else(|| Error::Unsolvable("the end cannot be reached".to_string()))
+}
+
+fn longest_from(graph: &Graph, node: usize, end: usize, seen: &mut [bool]) -> Option<usize> {
+    if node == end {
+        return Some(0);
+    }
+    seen[node] = true;
+    let mut longest = None;
+    for &(next, len) in graph.edges(node) {
+        if seen[next] {
+            continue;
+        }
+        if let Some(rest) = longest_from(graph, next, end, seen) {
+            longest = longest.max(Some(rest + len));
+        }
+    }
+    seen[node] = false;
+    longest
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    const TEST_INPUT: &str = include_str!("../inputs/day-23-test.txt");
+    #[test]
+    fn test_input() {
+        assert_eq!(Day23::solve_part_1(TEST_INPUT).unwrap(), 94);
+        assert_eq!(Day23::solve_part_2(TEST_INPUT).unwrap(), 154);
+    }
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(
+            Day23::solve_part_1(include_str!("../inputs/day-23.txt")).unwrap(),
+            2_034
+        );
+    }
+
+    #[test]
+    fn test_part_2() {
+        assert_eq!(
+            Day23::solve_part_2(include_str!("../inputs/day-23.txt")).unwrap(),
+            6_302
+        );
+    }
+}
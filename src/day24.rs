@@ -0,0 +1,175 @@
+//! Day 24: Never Tell Me The Odds
+
+use crate::{err, error, solution::Solution, utils::Rational, Error, Result};
+use itertools::Itertools;
+
+use std::ops::RangeInclusive;
+
+pub struct Day24;
+
+// Where hailstone paths must cross for part 1.
+const TEST_AREA: RangeInclusive<i128> = 200_000_000_000_000..=400_000_000_000_000;
+
+impl Solution for Day24 {
+    const DAY: u8 = 24;
+    type Input = Vec<Hailstone>;
+    type Part1 = usize;
+    type Part2 = i128;
+
+    fn parse(input: &str) -> Result<Vec<Hailstone>> {
+        input.lines().map(|line| parse_line(input, line)).collect()
+    }
+
+    fn part_1(hailstones: &Vec<Hailstone>) -> Result<usize> {
+        Ok(hailstones
+            .iter()
+            .tuple_combinations()
+            .filter(|(a, b)| crosses_in_area(a, b, &TEST_AREA))
+            .count())
+    }
+
+    fn part_2(hailstones: &Vec<Hailstone>) -> Result<i128> {
+        let [first, second, third, ..] = &hailstones[..] else {
+            return err!(Assumption, "there should be at least 3 hailstones");
+        };
+        throw_rock(first, second, third)
+    }
+}
+
+/// A hailstone's position and velocity, as `[x, y, z]`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Hailstone {
+    position: [i128; 3],
+    velocity: [i128; 3],
+}
+
+// Whether the paths of `a` and `b`, ignoring z, cross ahead of both of them
+// within `area` on x and y.
+fn crosses_in_area(a: &Hailstone, b: &Hailstone, area: &RangeInclusive<i128>) -> bool {
+    // a.p + t * a.v = b.p + s * b.v, by Cramer's rule
+    let [dx, dy] = [0, 1].map(|i| b.position[i] - a.position[i]);
+    let det = b.velocity[0] * a.velocity[1] - a.velocity[0] * b.velocity[1];
+    if det == 0 {
+        // parallel, hailstones on the same path never happen in the inputs
+        return false;
+    }
+    let t = Rational::new(b.velocity[0] * dy - dx * b.velocity[1], det);
+    let s = Rational::new(a.velocity[0] * dy - dx * a.velocity[1], det);
+    if t < Rational::ZERO || s < Rational::ZERO {
+        return false;
+    }
+    let (low, high) = (Rational::from(*area.start()), Rational::from(*area.end()));
+    [0, 1].into_iter().all(|i| {
+        let at = Rational::from(a.position[i]) + t * Rational::from(a.velocity[i]);
+        low <= at && at <= high
+    })
+}
+
+// The sum of the coordinates the rock must start at to hit every hailstone,
+// found from three of them. Seen from the first hailstone, the rock goes
+// through the origin, so it hits the second hailstone within the plane that
+// holds the origin and the second's path, and likewise for the third. When
+// it hits both gives its velocity, then its start.
+fn throw_rock(first: &Hailstone, second: &Hailstone, third: &Hailstone) -> Result<i128> {
+    let relative = |h: &Hailstone| {
+        (
+            sub(h.position, first.position),
+            sub(h.velocity, first.velocity),
+        )
+    };
+    let (p2, v2) = relative(second);
+    let (p3, v3) = relative(third);
+
+    // the time the rock hits the hailstone `(p, v)`, in the plane `normal`
+    let hit = |normal: [i128; 3], p: [i128; 3], v: [i128; 3]| {
+        let speed = dot(normal, v);
+        (speed != 0).then(|| Rational::new(-dot(normal, p), speed))
+    };
+    let (Some(t2), Some(t3)) = (hit(cross(p3, v3), p2, v2), hit(cross(p2, v2), p3, v3)) else {
+        return err!(Unsolvable, "the hailstones leave no single throw");
+    };
+    if t2 == t3 {
+        return err!(Unsolvable, "the rock would hit two hailstones at once");
+    }
+
+    let at = |p: [i128; 3], v: [i128; 3], t: Rational| {
+        [0, 1, 2].map(|i| Rational::from(p[i]) + t * Rational::from(v[i]))
+    };
+    let (q2, q3) = (at(p2, v2, t2), at(p3, v3, t3));
+    let mut sum = Rational::ZERO;
+    for i in 0..3 {
+        let velocity = (q3[i] - q2[i]) / (t3 - t2);
+        // back to the first hailstone's frame, where it was at the start
+        sum = sum + q2[i] - t2 * velocity + Rational::from(first.position[i]);
+    }
+    sum.to_integer()
+        .ok_or_else(|| Error::Unsolvable(format!("the rock would start at a fraction, {sum}")))
+}
+
+fn sub(a: [i128; 3], b: [i128; 3]) -> [i128; 3] {
+    [a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+}
+
+fn dot(a: [i128; 3], b: [i128; 3]) -> i128 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+fn cross(a: [i128; 3], b: [i128; 3]) -> [i128; 3] {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+fn parse_line(input: &str, line: &str) -> Result<Hailstone> {
+    let (position, velocity) = error::split_once(input, line, " @ ")?;
+    let parse = |numbers: &str| -> Result<[i128; 3]> {
+        let parsed = numbers
+            .split(',')
+            .map(|n| error::parse::<i128>(input, n.trim()))
+            .collect::<Result<Vec<_>>>()?;
+        parsed
+            .try_into()
+            .map_err(|_| Error::at(input, numbers, "expected x, y and z"))
+    };
+    Ok(Hailstone {
+        position: parse(position)?,
+        velocity: parse(velocity)?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    const TEST_INPUT: &str = include_str!("../inputs/day-24-test.txt");
+
+    #[test]
+    fn test_input() {
+        // the example's own test area
+        let hailstones = Day24::parse(TEST_INPUT).unwrap();
+        let crossing = hailstones
+            .iter()
+            .tuple_combinations()
+            .filter(|(a, b)| crosses_in_area(a, b, &(7..=27)))
+            .count();
+        assert_eq!(crossing, 2);
+        assert_eq!(Day24::solve_part_2(TEST_INPUT).unwrap(), 47);
+    }
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(
+            Day24::solve_part_1(include_str!("../inputs/day-24.txt")).unwrap(),
+            29_142
+        );
+    }
+
+    #[test]
+    fn test_part_2() {
+        assert_eq!(
+            Day24::solve_part_2(include_str!("../inputs/day-24.txt")).unwrap(),
+            848_947_587_263_033
+        );
+    }
+}
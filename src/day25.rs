@@ -0,0 +1,78 @@
+//! Day 25: Snowverload
+
+use crate::{err, error, solution::Solution, Result};
+use itertools::Itertools;
+use priority_queue::PriorityQueue;
+use rustworkx_core::{connectivity::stoer_wagner_min_cut, petgraph::prelude::UnGraph};
+
+use std::{
+    collections::{HashMap, HashSet},
+    vec,
+};
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    const DAY: u8 = 25;
+    type Input = Vec<(String, Vec<String>)>;
+    type Part1 = usize;
+    type Part2 = char;
+
+    fn parse(input: &str) -> Result<Vec<(String, Vec<String>)>> {
+        input
+            .lines()
+            .map(|line| {
+                let (node, edges) = error::split_once(input, line, ": ")?;
+                Ok((
+                    node.to_string(),
+                    edges.split(' ').map(String::from).collect(),
+                ))
+            })
+            .collect()
+    }
+
+    fn part_1(wiring: &Vec<(String, Vec<String>)>) -> Result<usize> {
+        part_1(wiring)
+    }
+
+    // there is no second puzzle on the last day
+    fn part_2(_: &Vec<(String, Vec<String>)>) -> Result<char> {
+        Ok('🎄')
+    }
+}
+
+fn part_1(wiring: &[(String, Vec<String>)]) -> Result<usize> {
+    let mut graph = rustworkx_core::petgraph::Graph::new_undirected();
+    let mut nodes = HashMap::new();
+    for (node, edges) in wiring {
+        let node = *nodes.entry(node).or_insert_with(|| graph.add_node(node));
+        for edge in edges {
+            let edge = *nodes.entry(edge).or_insert_with(|| graph.add_node(edge));
+            graph.add_edge(node, edge, ());
+        }
+    }
+    // the runner times and prints every day, so nothing is printed here
+    match stoer_wagner_min_cut(&graph, |_| Ok::<i32, ()>(1)) {
+        Err(_) => unreachable!(),
+        Ok(None) => err!(Unsolvable, "the wiring cannot be cut in two"),
+        Ok(Some((_, partition))) => Ok(partition.len() * (nodes.len() - partition.len())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    const TEST_INPUT: &str = include_str!("../inputs/day-25-test.txt");
+    #[test]
+    fn test_input() {
+        assert_eq!(Day25::solve_part_1(TEST_INPUT).unwrap(), 54);
+    }
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(
+            Day25::solve_part_1(include_str!("../inputs/day-25.txt")).unwrap(),
+            552_695
+        );
+    }
+}
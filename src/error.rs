@@ -0,0 +1,153 @@
+use std::{fmt::Display, path::Path, str::FromStr};
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// Everything a day can fail with.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// The input is malformed. Lines and columns count from 1.
+    Parse {
+        line: usize,
+        col: usize,
+        message: String,
+    },
+    /// The input is well formed but breaks something the solution relies on,
+    /// e.g. a shape of the input the puzzle never promised.
+    Assumption(String),
+    /// The input is fine but has no answer.
+    Unsolvable(String),
+    /// A file of the runner could not be read or written.
+    Io(String),
+    /// The runner was asked for something that makes no sense, e.g. an
+    /// unknown option value or a day that is not in the calendar.
+    Usage(String),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parse { line, col, message } => write!(f, "line {line}, col {col}: {message}"),
+            Error::Assumption(message) => write!(f, "invalid assumption: {message}"),
+            Error::Unsolvable(message) => write!(f, "no solution: {message}"),
+            Error::Io(message) | Error::Usage(message) => write!(f, "{message}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl Error {
+    /// A parse error at `token`, which must be a slice of `input` for the
+    /// position to be found.
+    pub fn at(input: &str, token: &str, message: impl Display) -> Self {
+        let (line, col) = position(input, token);
+        Error::Parse {
+            line,
+            col,
+            message: message.to_string(),
+        }
+    }
+
+    /// Failing to `action` the file at `path`, e.g. `"read answers from"`.
+    pub fn io(action: &str, path: &Path, error: impl Display) -> Self {
+        Error::Io(format!("cannot {action} {}: {error}", path.display()))
+    }
+
+    /// Move a parse error found in `slice` onto `input`, which `slice` is part
+    /// of. Handy with `FromStr` implementations that only see one line.
+    pub fn within(self, input: &str, slice: &str) -> Self {
+        match (self, position(input, slice)) {
+            (Error::Parse { line, col, message }, (start_line, start_col))
+                if line > 0 && start_line > 0 =>
+            {
+                Error::Parse {
+                    line: start_line + line - 1,
+                    col: if line == 1 { start_col + col - 1 } else { col },
+                    message,
+                }
+            }
+            (e, _) => e,
+        }
+    }
+}
+
+// Line and column of the start of `token` within `input`, (0, 0) if `token` is
+// not a slice of `input`.
+fn position(input: &str, token: &str) -> (usize, usize) {
+    let offset = (token.as_ptr() as usize).wrapping_sub(input.as_ptr() as usize);
+    if offset > input.len() {
+        return (0, 0);
+    }
+    let before = &input[..offset];
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    (
+        before.matches('\n').count() + 1,
+        before[line_start..].chars().count() + 1,
+    )
+}
+
+/// `token` was not expected here.
+pub fn unexpected(input: &str, token: &str) -> Error {
+    match token.chars().next() {
+        None => Error::at(input, token, "unexpected end of input"),
+        Some(c) if token.len() == c.len_utf8() => {
+            Error::at(input, token, format!("unexpected {c:?}"))
+        }
+        Some(_) => Error::at(input, token, format!("unexpected {token:?}")),
+    }
+}
+
+/// The character at byte `index` of `line` was not expected here.
+pub fn unexpected_char(input: &str, line: &str, index: usize) -> Error {
+    let len = line[index..].chars().next().map_or(0, char::len_utf8);
+    unexpected(input, &line[index..index + len])
+}
+
+/// Parse `token` as a `T`, reporting where it failed.
+pub fn parse<T: FromStr>(input: &str, token: &str) -> Result<T> {
+    token.parse().map_err(|_| unexpected(input, token))
+}
+
+/// `s.split_once(delimiter)`, reporting the missing delimiter at the end of `s`.
+pub fn split_once<'a>(input: &str, s: &'a str, delimiter: &str) -> Result<(&'a str, &'a str)> {
+    s.split_once(delimiter)
+        .ok_or_else(|| Error::at(input, &s[s.len()..], format!("expected {delimiter:?}")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_position() {
+        let input = "1,2,3\n4,@,6\n";
+        let token = &input[8..9];
+        assert_eq!(
+            unexpected(input, token).to_string(),
+            "line 2, col 3: unexpected '@'"
+        );
+        assert_eq!(
+            parse::<u32>(input, token),
+            Err(Error::Parse {
+                line: 2,
+                col: 3,
+                message: String::from("unexpected '@'")
+            })
+        );
+        assert_eq!(
+            split_once(input, &input[..5], ":").unwrap_err().to_string(),
+            "line 1, col 6: expected \":\""
+        );
+        let line = &input[6..11];
+        assert_eq!(
+            unexpected(line, &line[2..3])
+                .within(input, line)
+                .to_string(),
+            "line 2, col 3: unexpected '@'"
+        );
+        assert_eq!(
+            Error::at(input, "@", "elsewhere").to_string(),
+            "line 0, col 0: elsewhere"
+        );
+    }
+}
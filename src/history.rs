@@ -0,0 +1,371 @@
+use std::{
+    fmt::Display,
+    fs::{self, OpenOptions},
+    io::Write,
+    path::{Path, PathBuf},
+    process::Command,
+    str::FromStr,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use crate::{err, runner::DayRun, Error, Result};
+
+pub const HEADER: &str = "revision,date,profile,threads,day,part,parse_ns,time_ns";
+
+// Parts faster than this are too noisy to call a slowdown a regression.
+pub const NOISE_FLOOR: Duration = Duration::from_micros(100);
+
+/// The timing of one part in one recorded run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Sample {
+    pub revision: String,
+    pub date: String,
+    // `debug` or `release`, runs are only compared within the same profile
+    pub profile: String,
+    // the days run side by side, runs are only compared with the same count
+    pub threads: usize,
+    pub day: u8,
+    pub part: u8,
+    pub parse: Duration,
+    pub elapsed: Duration,
+}
+
+impl Sample {
+    // Parsing is shared by both parts, but counts towards each of them.
+    pub fn total(&self) -> Duration {
+        self.parse + self.elapsed
+    }
+
+    fn run(&self) -> (&str, &str, &str, usize) {
+        (&self.revision, &self.date, &self.profile, self.threads)
+    }
+
+    // Whether the timings of the two samples' runs can be compared.
+    fn comparable(&self, other: &Sample) -> bool {
+        self.profile == other.profile && self.threads == other.threads
+    }
+}
+
+/// Every recorded run, oldest first. Stored as CSV with a header, one row per
+/// day and part of each run.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct History {
+    samples: Vec<Sample>,
+}
+
+pub fn default_path() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("history.csv")
+}
+
+impl History {
+    /// Loads the history at `path`, which is empty if the file does not exist yet.
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        fs::read_to_string(path)
+            .map_err(|e| Error::io("read history from", path, e))?
+            .parse()
+    }
+
+    /// Appends `samples` to the history at `path`, creating it if needed.
+    pub fn append(path: &Path, samples: &[Sample]) -> Result<()> {
+        let new = !path.exists();
+        let mut file = OpenOptions::new()
+            .append(true)
+            .create(true)
+            .open(path)
+            .map_err(|e| Error::io("write history to", path, e))?;
+        let mut out = String::new();
+        if new {
+            out.push_str(HEADER);
+            out.push('\n');
+        }
+        for sample in samples {
+            out.push_str(&format!(
+                "{},{},{},{},{},{},{},{}\n",
+                sample.revision,
+                sample.date,
+                sample.profile,
+                sample.threads,
+                sample.day,
+                sample.part,
+                sample.parse.as_nanos(),
+                sample.elapsed.as_nanos()
+            ));
+        }
+        file.write_all(out.as_bytes())
+            .map_err(|e| Error::io("write history to", path, e))
+    }
+
+    // The samples of each run, oldest run first.
+    fn runs(&self) -> Vec<&[Sample]> {
+        self.samples.chunk_by(|a, b| a.run() == b.run()).collect()
+    }
+}
+
+impl FromStr for History {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut lines = s.lines().filter(|line| !line.trim().is_empty());
+        match lines.next() {
+            Some(header) if header.trim() == HEADER => {}
+            None => return Ok(Self::default()),
+            Some(line) => return Err(Error::at(s, line, format!("expected the header {HEADER}"))),
+        }
+        let mut samples = Vec::new();
+        for line in lines {
+            let fields = line.split(',').map(str::trim).collect::<Vec<_>>();
+            let [revision, date, profile, threads, day, part, parse, elapsed] = fields[..] else {
+                return Err(Error::at(s, line, format!("expected {HEADER}")));
+            };
+            let parsed = (
+                threads.parse::<usize>(),
+                day.parse::<u8>(),
+                part.parse::<u8>(),
+                parse.parse::<u64>(),
+                elapsed.parse::<u64>(),
+            );
+            let (Ok(threads @ 1..), Ok(day), Ok(part @ 1..=2), Ok(parse), Ok(elapsed)) = parsed
+            else {
+                return Err(Error::at(s, line, "bad number"));
+            };
+            samples.push(Sample {
+                revision: revision.to_string(),
+                date: date.to_string(),
+                profile: profile.to_string(),
+                threads,
+                day,
+                part,
+                parse: Duration::from_nanos(parse),
+                elapsed: Duration::from_nanos(elapsed),
+            });
+        }
+        Ok(Self { samples })
+    }
+}
+
+/// The samples of a run of the runner on `threads` threads, skipping the parts
+/// that did not finish.
+pub fn samples(runs: &[DayRun], threads: usize, revision: &str, date: &str) -> Vec<Sample> {
+    let profile = if cfg!(debug_assertions) {
+        "debug"
+    } else {
+        "release"
+    };
+    runs.iter()
+        .flat_map(|run| {
+            run.parts
+                .iter()
+                .filter(|part| part.answer.is_some())
+                .map(|part| Sample {
+                    revision: revision.to_string(),
+                    date: date.to_string(),
+                    profile: profile.to_string(),
+                    threads,
+                    day: run.day,
+                    part: part.part,
+                    parse: run.parse,
+                    elapsed: part.elapsed,
+                })
+        })
+        .collect()
+}
+
+/// The checked-out git revision, `-dirty` if there are uncommitted changes.
+pub fn revision() -> String {
+    Command::new("git")
+        .args(["describe", "--always", "--dirty"])
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .and_then(|output| String::from_utf8(output.stdout).ok())
+        .map(|revision| revision.trim().to_string())
+        .unwrap_or_else(|| String::from("unknown"))
+}
+
+/// The current UTC date and time, as `2023-12-25T06:00:00Z`.
+pub fn now() -> String {
+    let secs = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs());
+    date(secs)
+}
+
+// Civil date from days since the epoch, see
+// https://howardhinnant.github.io/date_algorithms.html#civil_from_days
+fn date(secs: u64) -> String {
+    let (days, secs) = ((secs / 86_400) as i64, secs % 86_400);
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}Z",
+        secs / 3_600,
+        secs / 60 % 60,
+        secs % 60
+    )
+}
+
+/// What the latest run is compared against.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Baseline {
+    /// The fastest time of each part over all the earlier runs.
+    Best,
+    /// The run just before.
+    #[default]
+    Previous,
+}
+
+impl FromStr for Baseline {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "best" => Ok(Baseline::Best),
+            "previous" => Ok(Baseline::Previous),
+            _ => err!(Usage, "unknown baseline {s}, expected best or previous"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Comparison {
+    pub day: u8,
+    pub part: u8,
+    pub latest: Duration,
+    pub baseline: Duration,
+    // slower by this many percent, negative when faster
+    pub change: f64,
+    pub regressed: bool,
+}
+
+// Compare every part of the latest run against `baseline`, among the earlier
+// runs with the same profile and thread count, since days timed side by side
+// are slowed by each other. A part regressed when it is more than
+// `threshold` percent slower, and slower than `NOISE_FLOOR` to begin with.
+pub fn compare(history: &History, baseline: Baseline, threshold: f64) -> Vec<Comparison> {
+    let runs = history.runs();
+    let Some((latest, earlier)) = runs.split_last() else {
+        return Vec::new();
+    };
+    let earlier = earlier
+        .iter()
+        .filter(|run| run[0].comparable(&latest[0]))
+        .collect::<Vec<_>>();
+    let candidates = match baseline {
+        Baseline::Best => earlier,
+        Baseline::Previous => earlier.last().into_iter().copied().collect(),
+    };
+
+    latest
+        .iter()
+        .filter_map(|sample| {
+            let reference = candidates
+                .iter()
+                .flat_map(|run| run.iter())
+                .filter(|s| s.day == sample.day && s.part == sample.part)
+                .map(Sample::total)
+                .min()?;
+            let change = (sample.total().as_secs_f64() / reference.as_secs_f64() - 1.0) * 100.0;
+            Some(Comparison {
+                day: sample.day,
+                part: sample.part,
+                latest: sample.total(),
+                baseline: reference,
+                change,
+                regressed: change > threshold && sample.total() > NOISE_FLOOR,
+            })
+        })
+        .collect()
+}
+
+impl Display for Comparison {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{:>3}  {:>4}  {:>12.3}  {:>12.3}  {:>+8.1}%{}",
+            self.day,
+            self.part,
+            self.latest.as_secs_f64() * 1_000.0,
+            self.baseline.as_secs_f64() * 1_000.0,
+            self.change,
+            if self.regressed { "  SLOWER" } else { "" }
+        )
+    }
+}
+
+pub fn table(comparisons: &[Comparison]) -> String {
+    let mut out = format!(
+        "{:>3}  {:>4}  {:>12}  {:>12}  {:>9}\n",
+        "day", "part", "latest (ms)", "before (ms)", "change"
+    );
+    for comparison in comparisons {
+        out.push_str(&format!("{comparison}\n"));
+    }
+    let regressed = comparisons.iter().filter(|c| c.regressed).count();
+    out.push_str(&format!("\n{regressed} part(s) got slower\n"));
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn history() -> History {
+        concat!(
+            "revision,date,profile,threads,day,part,parse_ns,time_ns\n",
+            "a1,2023-12-01T00:00:00Z,release,1,1,1,1000,2000000\n",
+            "a1,2023-12-01T00:00:00Z,release,1,1,2,1000,9000\n",
+            "b2,2023-12-02T00:00:00Z,release,1,1,1,1000,1000000\n",
+            "b2,2023-12-02T00:00:00Z,debug,1,1,1,1000,9000000\n",
+            "b2,2023-12-02T00:00:00Z,release,8,1,1,1000,100000\n",
+            "c3,2023-12-03T00:00:00Z,release,1,1,1,1000,1500000\n",
+            "c3,2023-12-03T00:00:00Z,release,1,1,2,1000,90000\n",
+        )
+        .parse()
+        .unwrap()
+    }
+
+    #[test]
+    fn test_compare() {
+        let history = history();
+        let previous = compare(&history, Baseline::Previous, 20.0);
+        assert_eq!(previous.len(), 1);
+        assert_eq!(previous[0].baseline, Duration::from_nanos(1_001_000));
+        assert!(previous[0].regressed);
+
+        let best = compare(&history, Baseline::Best, 60.0);
+        assert_eq!(best.len(), 2);
+        // not against the faster looking run on 8 threads
+        assert_eq!(best[0].baseline, Duration::from_nanos(1_001_000));
+        assert!(!best[0].regressed);
+        // ten times slower, but below the noise floor
+        assert!(best[1].change > 100.0 && !best[1].regressed);
+    }
+
+    #[test]
+    fn test_append() {
+        let path = std::env::temp_dir().join(format!("aoc-history-{}.csv", std::process::id()));
+        let samples = history().samples;
+        History::append(&path, &samples[..2]).unwrap();
+        History::append(&path, &samples[2..]).unwrap();
+        assert_eq!(History::load(&path).unwrap(), history());
+        fs::remove_file(&path).unwrap();
+        assert!("revision\n".parse::<History>().is_err());
+    }
+
+    #[test]
+    fn test_date() {
+        assert_eq!(date(0), "1970-01-01T00:00:00Z");
+        assert_eq!(date(1_703_484_000), "2023-12-25T06:00:00Z");
+    }
+}
@@ -0,0 +1,188 @@
+use std::{
+    borrow::Cow,
+    fmt::Display,
+    fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+/// Where the puzzle input of a day is read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    /// `inputs/day-N.txt` at the root of the repository.
+    Default,
+    /// Everything piped into the process.
+    Stdin,
+    /// Any other file, e.g. another account's input or an example.
+    Path(PathBuf),
+}
+
+impl Source {
+    // `-` means stdin, anything else is a path and nothing falls back to the
+    // default input of the day.
+    pub fn from_arg(arg: Option<&str>) -> Self {
+        match arg {
+            None => Self::Default,
+            Some("-") => Self::Stdin,
+            Some(path) => Self::Path(PathBuf::from(path)),
+        }
+    }
+}
+
+impl Display for Source {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Source::Default => write!(f, "default input"),
+            Source::Stdin => write!(f, "stdin"),
+            Source::Path(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+pub fn default_path(day: u8) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("inputs")
+        .join(format!("day-{day}.txt"))
+}
+
+/// A directory of real inputs, one `day-N.txt` per day it has, e.g. another
+/// account's inputs in `inputs/<set>/`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InputSet {
+    pub name: String,
+    pub dir: PathBuf,
+}
+
+impl InputSet {
+    pub const DEFAULT: &'static str = "default";
+
+    pub fn path(&self, day: u8) -> PathBuf {
+        self.dir.join(format!("day-{day}.txt"))
+    }
+
+    // Where the input of `day` lives relative to the inputs directory, as the
+    // answers manifest names it.
+    pub fn file(&self, day: u8) -> String {
+        match self.name.as_str() {
+            Self::DEFAULT => format!("day-{day}.txt"),
+            name => format!("{name}/day-{day}.txt"),
+        }
+    }
+
+    /// The input of `day`, if this set has one.
+    pub fn source(&self, day: u8) -> Option<Source> {
+        let path = self.path(day);
+        path.is_file().then_some(Source::Path(path))
+    }
+}
+
+/// The input sets in `inputs_dir`: the directory itself as `default`, then
+/// every subdirectory by name.
+pub fn sets(inputs_dir: &Path) -> io::Result<Vec<InputSet>> {
+    let mut sets = Vec::new();
+    for entry in fs::read_dir(inputs_dir)? {
+        let path = entry?.path();
+        if let (true, Some(name)) = (path.is_dir(), path.file_name().and_then(|n| n.to_str())) {
+            sets.push(InputSet {
+                name: name.to_string(),
+                dir: path.clone(),
+            });
+        }
+    }
+    sets.sort_by(|a, b| a.name.cmp(&b.name));
+    sets.insert(
+        0,
+        InputSet {
+            name: String::from(InputSet::DEFAULT),
+            dir: inputs_dir.to_path_buf(),
+        },
+    );
+    Ok(sets)
+}
+
+// The input is returned normalised, see `normalise`.
+pub fn load(day: u8, source: &Source) -> io::Result<String> {
+    let path = match source {
+        Source::Stdin => {
+            let mut input = String::new();
+            io::stdin().read_to_string(&mut input)?;
+            return Ok(normalise(&input).into_owned());
+        }
+        Source::Default => default_path(day),
+        Source::Path(path) => path.clone(),
+    };
+    let input = fs::read_to_string(&path).map_err(|e| {
+        io::Error::new(
+            e.kind(),
+            format!(
+                "cannot read input of day {day} from {}: {e}",
+                path.display()
+            ),
+        )
+    })?;
+    Ok(normalise(&input).into_owned())
+}
+
+/// The input as every day parses it: `\n` line endings, no byte order mark
+/// and no trailing whitespace, neither at the end of a line nor of the input.
+/// Lines keep their numbers, so parse errors still point at the right place.
+pub fn normalise(raw: &str) -> Cow<'_, str> {
+    let raw = raw.strip_prefix('\u{feff}').unwrap_or(raw);
+    let is_normal = !raw.contains('\r')
+        && !raw.ends_with(char::is_whitespace)
+        && raw.lines().all(|line| !line.ends_with(char::is_whitespace));
+    if is_normal {
+        return Cow::Borrowed(raw);
+    }
+    let lines = raw.lines().map(str::trim_end).collect::<Vec<_>>();
+    Cow::Owned(lines.join("\n").trim_end().to_string())
+}
+
+/// Lines of a normalised input.
+pub fn lines(input: &str) -> impl Iterator<Item = &str> {
+    input.lines()
+}
+
+/// Blocks of lines separated by an empty line, e.g. the seeds and each map
+/// of day 5.
+pub fn blocks(input: &str) -> impl Iterator<Item = &str> {
+    input.split("\n\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sets() {
+        let dir = std::env::temp_dir().join(format!("aoc-sets-{}", std::process::id()));
+        fs::create_dir_all(dir.join("bob")).unwrap();
+        fs::create_dir_all(dir.join("alice")).unwrap();
+        fs::write(dir.join("alice").join("day-8.txt"), "RL").unwrap();
+        fs::write(dir.join("day-8.txt"), "LR").unwrap();
+
+        let sets = sets(&dir).unwrap();
+        assert_eq!(
+            sets.iter().map(|set| set.name.as_str()).collect::<Vec<_>>(),
+            vec!["default", "alice", "bob"]
+        );
+        assert_eq!(sets[0].file(8), "day-8.txt");
+        assert_eq!(sets[1].file(8), "alice/day-8.txt");
+        assert_eq!(
+            sets[1].source(8),
+            Some(Source::Path(dir.join("alice").join("day-8.txt")))
+        );
+        assert_eq!(sets[2].source(8), None);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_normalise() {
+        assert_eq!(normalise("a\nb"), Cow::Borrowed("a\nb"));
+        assert_eq!(normalise("\u{feff}a  \r\n\r\nb\r\n\r\n"), "a\n\nb");
+        assert_eq!(
+            blocks(&normalise("a\r\nb\r\n \r\nc\n")).collect::<Vec<_>>(),
+            vec!["a\nb", "c"]
+        );
+    }
+}
@@ -1,19 +1,134 @@
+//! Advent of Code 2023. Every day is a module (`day05`, `day07`, ...) with a
+//! `DayNN` type implementing [`Solution`](solution::Solution), which parses an
+//! input and solves both parts, next to the types the puzzle is modelled with.
+//!
+//! ```
+//! use aoc_2023_lib::{day07::Deck, solution::Solution, Day07};
+//!
+//! let input = "32T3K 765\nT55J5 684\nKK677 28\nKTJJT 220\nQQQJA 483";
+//! assert_eq!(Day07::solve_part_1(input).unwrap(), 6440);
+//! let deck = input.parse::<Deck>().unwrap();
+//! assert_eq!(deck.winnings(true), 5905);
+//! ```
+
+// Run a day against its input. The input is read at runtime from the path
+// given as first argument, from stdin when that argument is `-`, or from
+// `inputs/day-N.txt` otherwise. With `--bench` in front, parsing and both parts
+// are benchmarked instead.
 #[macro_export]
 macro_rules! main {
-    ($($x:tt)+) => {
-        fn main(){
-            let now = std::time::Instant::now();
-            let (p1, p2) = {$($x)+};
-            let time = now.elapsed().as_millis();
-            println!("Part one: {}", p1);
-            println!("Part two: {}", p2);
-            println!("Time: {} ms", time);
+    ($solution:ty) => {
+        fn main() {
+            $crate::runner::run_binary::<$solution>();
         }
     };
 }
+// `err!(Unsolvable, "no path to {end}")` is an `Err` of the given `Error`
+// variant with a formatted message.
 #[macro_export]
 macro_rules! err {
-    ($($tt:tt)*) => { Err(Box::<dyn Error>::from(format!($($tt)*))) }
+    ($kind:ident, $($tt:tt)*) => { Err($crate::Error::$kind(format!($($tt)*))) }
 }
 
+pub mod answers;
+pub mod bench;
+pub mod error;
+pub mod history;
+pub mod input;
+pub mod memory;
+pub mod report;
+pub mod runner;
+pub mod scaffold;
+pub mod solution;
 pub mod utils;
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day17_lib;
+pub mod day18;
+pub mod day19;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+
+pub use day01::Day01;
+pub use day02::Day02;
+pub use day03::Day03;
+pub use day04::Day04;
+pub use day05::Day05;
+pub use day06::Day06;
+pub use day07::Day07;
+pub use day08::Day08;
+pub use day09::Day09;
+pub use day10::Day10;
+pub use day11::Day11;
+pub use day12::Day12;
+pub use day13::Day13;
+pub use day14::Day14;
+pub use day15::Day15;
+pub use day16::Day16;
+pub use day17::Day17;
+pub use day17_lib::Day17Lib;
+pub use day18::Day18;
+pub use day19::Day19;
+pub use day20::Day20;
+pub use day21::Day21;
+pub use day22::Day22;
+pub use day23::Day23;
+pub use day24::Day24;
+pub use day25::Day25;
+
+pub use error::{Error, Result};
+
+use solution::Registry;
+
+// Every day of the calendar. Day 17 also has `Day17Lib`, which is only
+// reachable through its own binary.
+pub fn registry() -> Registry {
+    let mut registry = Registry::new();
+    registry
+        .register::<Day01>()
+        .register::<Day02>()
+        .register::<Day03>()
+        .register::<Day04>()
+        .register::<Day05>()
+        .register::<Day06>()
+        .register::<Day07>()
+        .register::<Day08>()
+        .register::<Day09>()
+        .register::<Day10>()
+        .register::<Day11>()
+        .register::<Day12>()
+        .register::<Day13>()
+        .register::<Day14>()
+        .register::<Day15>()
+        .register::<Day16>()
+        .register::<Day17>()
+        .register::<Day18>()
+        .register::<Day19>()
+        .register::<Day20>()
+        .register::<Day21>()
+        .register::<Day22>()
+        .register::<Day23>()
+        .register::<Day24>()
+        .register::<Day25>();
+    registry
+}
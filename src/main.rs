@@ -1,26 +1,250 @@
-use once_cell::sync::Lazy;
-use regex::Regex;
-use std::process::Command;
+use aoc_2023_lib::{
+    answers::{self, Manifest, Verdict},
+    bench::{self, Config},
+    history::{self, Baseline, History},
+    input::{self, Source},
+    registry,
+    report::{self, Format},
+    runner, scaffold,
+    solution::Entry,
+};
+use std::{
+    path::Path,
+    process,
+    time::{Duration, Instant},
+};
 
-static MS_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"Time: (\d+) ms").unwrap());
+const USAGE: &str =
+    "usage: aoc [all] [-j <threads>] [--timeout <seconds>] [--format text|json|csv] [--no-history]
+       aoc run <day> [input|-] [--timeout <seconds>] [--format text|json|csv]
+       aoc sets [-j <threads>] [--timeout <seconds>] [--format text|json|csv]
+       aoc bench [day]
+       aoc check [answers.csv]
+       aoc compare [--against previous|best] [--threshold <percent>]
+       aoc new-day <day>";
 
-fn extract_time(s: &str) -> u32 {
-    let capture = MS_REGEX.captures_iter(s).next().unwrap();
-    capture[1].parse().unwrap()
+struct Options {
+    threads: usize,
+    // per day, `--timeout 0` waits forever
+    timeout: Option<Duration>,
+    format: Format,
+    // whether `aoc all` appends its timings to the history
+    history: bool,
+    baseline: Baseline,
+    // percent
+    threshold: f64,
+}
+
+// Split the flags from the positional arguments.
+fn parse_args(args: &[String]) -> Result<(Vec<&str>, Options), String> {
+    let mut positional = Vec::new();
+    let mut options = Options {
+        threads: 1,
+        timeout: Some(Duration::from_secs(60)),
+        format: Format::Text,
+        history: true,
+        baseline: Baseline::Previous,
+        threshold: 20.0,
+    };
+    let mut args = args.iter().map(String::as_str);
+    while let Some(arg) = args.next() {
+        match arg {
+            "-j" | "--threads" => {
+                options.threads = match args.next().map(str::parse::<usize>) {
+                    Some(Ok(threads)) if threads > 0 => threads,
+                    _ => return Err(format!("{arg} expects a number of threads\n{USAGE}")),
+                }
+            }
+            "--timeout" => {
+                options.timeout = match args.next().map(str::parse::<f64>) {
+                    Some(Ok(0.0)) => None,
+                    Some(Ok(seconds)) if seconds > 0.0 => Some(Duration::from_secs_f64(seconds)),
+                    _ => return Err(format!("{arg} expects a number of seconds\n{USAGE}")),
+                }
+            }
+            "--format" => {
+                options.format = args
+                    .next()
+                    .ok_or_else(|| format!("{arg} expects a format\n{USAGE}"))?
+                    .parse()
+                    .map_err(|e| format!("{e}\n{USAGE}"))?
+            }
+            "--no-history" => options.history = false,
+            "--against" => {
+                options.baseline = args
+                    .next()
+                    .ok_or_else(|| format!("{arg} expects previous or best\n{USAGE}"))?
+                    .parse()
+                    .map_err(|e| format!("{e}\n{USAGE}"))?
+            }
+            "--threshold" => {
+                options.threshold = match args.next().map(str::parse::<f64>) {
+                    Some(Ok(percent)) if percent >= 0.0 => percent,
+                    _ => return Err(format!("{arg} expects a percentage\n{USAGE}")),
+                }
+            }
+            _ => positional.push(arg),
+        }
+    }
+    Ok((positional, options))
+}
+
+// The commands below return how many days (or answers) failed, which becomes
+// the exit code.
+fn run_all(options: &Options) -> Result<usize, String> {
+    let now = Instant::now();
+    let runs = runner::run_all(&registry(), options.threads, options.timeout);
+    let wall_time = now.elapsed();
+
+    print!("{}", report::render(&runs, options.format));
+    if options.format == Format::Text {
+        let cpu_time = runs
+            .iter()
+            .map(|run| run.elapsed())
+            .sum::<std::time::Duration>();
+        println!(
+            "\nTotal time: {:.3} ms (wall: {:.3} ms on {} thread(s))",
+            cpu_time.as_secs_f64() * 1_000.0,
+            wall_time.as_secs_f64() * 1_000.0,
+            options.threads
+        );
+        let failed = runs
+            .iter()
+            .filter(|run| !run.is_ok())
+            .map(|run| {
+                let part = run.parts.iter().find(|part| !part.status.is_ok());
+                format!(
+                    "day-{} ({})",
+                    run.day,
+                    part.map_or("", |part| part.status.name())
+                )
+            })
+            .collect::<Vec<_>>();
+        if !failed.is_empty() {
+            println!("Failed: {}", failed.join(", "));
+        }
+    }
+    if options.history {
+        let samples = history::samples(
+            &runs,
+            options.threads,
+            &history::revision(),
+            &history::now(),
+        );
+        History::append(&history::default_path(), &samples).map_err(|e| e.to_string())?;
+    }
+    Ok(runner::failures(&runs))
+}
+
+// Run every day on every input set, see `input::sets`. Answers are checked
+// against the manifest when there is one.
+fn run_sets(options: &Options) -> Result<usize, String> {
+    let manifest_path = answers::default_path();
+    let inputs_dir = manifest_path.parent().unwrap_or(Path::new("."));
+    let sets = input::sets(inputs_dir).map_err(|e| e.to_string())?;
+    let manifest = match manifest_path.exists() {
+        true => Some(Manifest::load(&manifest_path).map_err(|e| e.to_string())?),
+        false => None,
+    };
+    let runs = runner::run_sets(&registry(), &sets, options.threads, options.timeout);
+
+    print!("{}", report::render_sets(&runs, options.format));
+    let (summary, failed) = report::summary(&runs, manifest.as_ref());
+    if options.format == Format::Text {
+        print!("{summary}");
+    }
+    Ok(failed)
+}
+
+// Compare the latest recorded run with the ones before, see `history::compare`.
+fn compare(options: &Options) -> Result<usize, String> {
+    let history = History::load(&history::default_path()).map_err(|e| e.to_string())?;
+    let comparisons = history::compare(&history, options.baseline, options.threshold);
+    if comparisons.is_empty() {
+        return Err(String::from(
+            "nothing to compare, the history needs two runs of `aoc all` with the same profile and -j",
+        ));
+    }
+    print!("{}", history::table(&comparisons));
+    Ok(comparisons.iter().filter(|c| c.regressed).count())
+}
+
+fn find_day(day: &str) -> Result<Entry, String> {
+    let day = day
+        .parse::<u8>()
+        .map_err(|_| format!("bad day {day}\n{USAGE}"))?;
+    registry()
+        .get(day)
+        .copied()
+        .ok_or_else(|| format!("there is no solution for day {day}"))
+}
+
+fn run_day(day: &str, source: &Source, options: &Options) -> Result<usize, String> {
+    let entry = find_day(day)?;
+    let run = runner::run_day_with_timeout(&entry, source, options.timeout);
+    let runs = std::slice::from_ref(&run);
+    print!("{}", report::render(runs, options.format));
+    Ok(runner::failures(runs))
+}
+
+fn bench_days(entries: &[Entry]) -> Result<usize, String> {
+    for entry in entries {
+        let raw = input::load(entry.day, &Source::Default).map_err(|e| e.to_string())?;
+        let bench = bench::bench(entry, &raw, &Config::default()).map_err(|e| e.to_string())?;
+        println!("{bench}");
+    }
+    Ok(0)
+}
+
+// Compare every day against the known answers. Inputs are looked up next to
+// the manifest.
+fn check(path: &Path) -> Result<usize, String> {
+    let manifest = Manifest::load(path).map_err(|e| e.to_string())?;
+    let inputs_dir = path.parent().unwrap_or(Path::new("."));
+    let checks = answers::check(&registry(), &manifest, inputs_dir);
+    print!("{}", answers::table(&checks));
+    Ok(checks
+        .iter()
+        .filter(|check| matches!(check.verdict, Verdict::Fail | Verdict::Error(_)))
+        .count())
+}
+
+// Generate the files of a new day in this repository.
+fn new_day(day: &str) -> Result<usize, String> {
+    let day = day
+        .parse::<u8>()
+        .map_err(|_| format!("bad day {day}\n{USAGE}"))?;
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    for path in scaffold::new_day(root, day).map_err(|e| e.to_string())? {
+        println!(
+            "wrote {}",
+            path.strip_prefix(root).unwrap_or(&path).display()
+        );
+    }
+    Ok(0)
 }
 
 fn main() {
-    let total_time = (1..=25)
-        .map(|day_num| {
-            let day = format!("day-{}", day_num);
-            let cmd = Command::new("cargo")
-                .args(["run", "--release", "--bin", &day])
-                .output()
-                .unwrap();
-            let output = String::from_utf8(cmd.stdout).unwrap();
-            println!("{}:\n{}", day, output);
-            extract_time(&output)
-        })
-        .sum::<u32>();
-    println!("Total time: {}ms", total_time);
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+    let result = parse_args(&args).and_then(|(positional, options)| match positional[..] {
+        [] | ["all"] => run_all(&options),
+        ["run", day] => run_day(day, &Source::Default, &options),
+        ["run", day, input] => run_day(day, &Source::from_arg(Some(input)), &options),
+        ["sets"] => run_sets(&options),
+        ["bench"] => bench_days(registry().entries()),
+        ["bench", day] => find_day(day).and_then(|entry| bench_days(&[entry])),
+        ["check"] => check(&answers::default_path()),
+        ["check", path] => check(Path::new(path)),
+        ["compare"] => compare(&options),
+        ["new-day", day] => new_day(day),
+        _ => Err(USAGE.to_string()),
+    });
+    match result {
+        Ok(0) => {}
+        Ok(failed) => process::exit(failed.min(255) as i32),
+        Err(e) => {
+            eprintln!("ERROR: {e}");
+            process::exit(1);
+        }
+    }
 }
@@ -0,0 +1,171 @@
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+    fmt::Display,
+};
+
+/// What a piece of code allocated, see `measure`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Stats {
+    pub allocations: u64,
+    pub bytes: u64,
+    // the most bytes alive at once, above what was alive before
+    pub peak: u64,
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} allocations, {} allocated, {} peak",
+            self.allocations,
+            human_bytes(self.bytes),
+            human_bytes(self.peak)
+        )
+    }
+}
+
+fn human_bytes(bytes: u64) -> String {
+    match bytes {
+        0..1_024 => format!("{bytes} B"),
+        1_024..1_048_576 => format!("{:.1} KiB", bytes as f64 / 1_024.0),
+        _ => format!("{:.1} MiB", bytes as f64 / 1_048_576.0),
+    }
+}
+
+#[derive(Clone, Copy)]
+struct Counters {
+    allocations: u64,
+    bytes: u64,
+    // signed, memory freed by another thread than the one that allocated it
+    // makes it go below zero
+    live: i64,
+    peak: i64,
+}
+
+// Counted per thread, so days running side by side on `-j` threads do not see
+// each other's allocations.
+thread_local! {
+    static COUNTERS: Cell<Counters> = const {
+        Cell::new(Counters {
+            allocations: 0,
+            bytes: 0,
+            live: 0,
+            peak: 0,
+        })
+    };
+}
+
+fn record(allocated: usize, freed: usize) {
+    // the thread may be tearing down its locals, then nothing is counted
+    let _ = COUNTERS.try_with(|counters| {
+        let mut c = counters.get();
+        if allocated > 0 {
+            c.allocations += 1;
+            c.bytes += allocated as u64;
+        }
+        c.live += allocated as i64 - freed as i64;
+        c.peak = c.peak.max(c.live);
+        counters.set(c);
+    });
+}
+
+/// The system allocator, counting what every thread allocates. Installed as
+/// the global allocator with the `alloc-stats` feature.
+pub struct Counting;
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            record(layout.size(), 0);
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            record(layout.size(), 0);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        record(0, layout.size());
+    }
+
+    // counted as a new allocation of `new_size` replacing the old one
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new = System.realloc(ptr, layout, new_size);
+        if !new.is_null() {
+            record(new_size, layout.size());
+        }
+        new
+    }
+}
+
+#[cfg(feature = "alloc-stats")]
+#[global_allocator]
+static GLOBAL: Counting = Counting;
+
+/// Whether allocations are counted at all, i.e. the `alloc-stats` feature.
+pub fn enabled() -> bool {
+    cfg!(feature = "alloc-stats")
+}
+
+// Run `f` and count what it allocates on this thread, `None` without the
+// `alloc-stats` feature. Measurements do not nest: an inner `measure` resets
+// the peak of the outer one.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<Stats>) {
+    if !enabled() {
+        return (f(), None);
+    }
+    let before = COUNTERS.with(|counters| {
+        let mut c = counters.get();
+        c.peak = c.live;
+        counters.set(c);
+        c
+    });
+    let result = f();
+    let after = COUNTERS.with(Cell::get);
+    let stats = Stats {
+        allocations: after.allocations - before.allocations,
+        bytes: after.bytes - before.bytes,
+        peak: (after.peak - before.live).max(0) as u64,
+    };
+    (result, Some(stats))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_measure() {
+        let (sum, stats) = measure(|| {
+            let v = vec![1u64; 1_000];
+            drop(vec![0u8; 100]);
+            v.iter().sum::<u64>()
+        });
+        assert_eq!(sum, 1_000);
+        match stats {
+            Some(stats) => {
+                assert_eq!(stats.allocations, 2);
+                assert_eq!(stats.bytes, 8_100);
+                assert_eq!(stats.peak, 8_100);
+            }
+            None => assert!(!enabled()),
+        }
+        assert_eq!(
+            Stats {
+                allocations: 3,
+                bytes: 2_048,
+                peak: 100
+            }
+            .to_string(),
+            "3 allocations, 2.0 KiB allocated, 100 B peak"
+        );
+    }
+}
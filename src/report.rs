@@ -0,0 +1,332 @@
+use std::{str::FromStr, time::Duration};
+
+use crate::{
+    answers::Manifest,
+    err,
+    memory::Stats,
+    runner::{DayRun, SetRun, Status},
+    solution::Answer,
+    Error, Result,
+};
+
+/// How the runner writes its results.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Format {
+    #[default]
+    Text,
+    JsonLines,
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" | "jsonl" => Ok(Format::JsonLines),
+            "csv" => Ok(Format::Csv),
+            _ => err!(Usage, "unknown format {s}, expected text, json or csv"),
+        }
+    }
+}
+
+/// One part of one day, flattened so it can be written as a single row.
+#[derive(Debug, Clone, Copy)]
+pub struct Record<'a> {
+    pub day: u8,
+    pub part: u8,
+    pub answer: Option<&'a Answer>,
+    // parsing is shared by both parts, so both rows carry the same value
+    pub parse: Duration,
+    pub elapsed: Duration,
+    pub status: &'a Status,
+    pub memory: Option<Stats>,
+}
+
+pub const CSV_HEADER: &str =
+    "day,part,answer,parse_ns,time_ns,status,error,allocations,bytes,peak_bytes";
+
+impl Record<'_> {
+    pub fn to_json(&self) -> String {
+        format!(
+            r#"{{"day":{},"part":{},"answer":{},"parse_ns":{},"time_ns":{},"status":"{}","error":{},"allocations":{},"bytes":{},"peak_bytes":{}}}"#,
+            self.day,
+            self.part,
+            self.answer
+                .map_or("null".to_string(), |a| json_string(&a.to_string())),
+            self.parse.as_nanos(),
+            self.elapsed.as_nanos(),
+            self.status.name(),
+            self.status
+                .message()
+                .map_or("null".to_string(), json_string),
+            self.memory
+                .map_or("null".to_string(), |m| m.allocations.to_string()),
+            self.memory
+                .map_or("null".to_string(), |m| m.bytes.to_string()),
+            self.memory
+                .map_or("null".to_string(), |m| m.peak.to_string()),
+        )
+    }
+
+    pub fn to_csv(&self) -> String {
+        format!(
+            "{},{},{},{},{},{},{},{},{},{}",
+            self.day,
+            self.part,
+            self.answer
+                .map_or(String::new(), |a| csv_field(&a.to_string())),
+            self.parse.as_nanos(),
+            self.elapsed.as_nanos(),
+            self.status.name(),
+            self.status.message().map_or(String::new(), csv_field),
+            self.memory
+                .map_or(String::new(), |m| m.allocations.to_string()),
+            self.memory.map_or(String::new(), |m| m.bytes.to_string()),
+            self.memory.map_or(String::new(), |m| m.peak.to_string()),
+        )
+    }
+}
+
+pub fn records(runs: &[DayRun]) -> impl Iterator<Item = Record<'_>> {
+    runs.iter().flat_map(|run| {
+        run.parts.iter().map(|part| Record {
+            day: run.day,
+            part: part.part,
+            answer: part.answer.as_ref(),
+            parse: run.parse,
+            elapsed: part.elapsed,
+            status: &part.status,
+            memory: part.memory,
+        })
+    })
+}
+
+pub fn render(runs: &[DayRun], format: Format) -> String {
+    match format {
+        Format::Text => runs
+            .iter()
+            .map(|run| format!("{run}\n"))
+            .collect::<Vec<_>>()
+            .join("\n"),
+        Format::JsonLines => records(runs).map(|r| r.to_json() + "\n").collect(),
+        Format::Csv => std::iter::once(CSV_HEADER.to_string() + "\n")
+            .chain(records(runs).map(|r| r.to_csv() + "\n"))
+            .collect(),
+    }
+}
+
+// Like `render`, once per input set. Text gets a heading per set, JSON Lines
+// and CSV a `set` field in front of every record.
+pub fn render_sets(sets: &[SetRun], format: Format) -> String {
+    let mut out = String::new();
+    if format == Format::Csv {
+        out.push_str(&format!("set,{CSV_HEADER}\n"));
+    }
+    for SetRun { set, runs } in sets {
+        match format {
+            Format::Text => {
+                out.push_str(&format!("== {} ==\n\n{}\n", set.name, render(runs, format)))
+            }
+            Format::JsonLines => records(runs).for_each(|r| {
+                let json = r.to_json();
+                out.push_str(&format!(
+                    "{{\"set\":{},{}\n",
+                    json_string(&set.name),
+                    &json[1..]
+                ))
+            }),
+            Format::Csv => records(runs)
+                .for_each(|r| out.push_str(&format!("{},{}\n", csv_field(&set.name), r.to_csv()))),
+        }
+    }
+    out
+}
+
+/// One row per day and part, one column per input set, so a solution that
+/// only works on some inputs stands out. A cell holds the answer, the status
+/// of a failed part, `FAIL <answer>` if it differs from the one in `manifest`,
+/// or `-` if the set has no input for the day. Also returns the number of
+/// failed and wrong cells.
+pub fn summary(sets: &[SetRun], manifest: Option<&Manifest>) -> (String, usize) {
+    let mut days = sets
+        .iter()
+        .flat_map(|set| set.runs.iter().map(|run| run.day))
+        .collect::<Vec<_>>();
+    days.sort_unstable();
+    days.dedup();
+
+    let mut failed = 0;
+    let mut rows = vec![[String::from("day"), String::from("part")]
+        .into_iter()
+        .chain(sets.iter().map(|set| set.set.name.clone()))
+        .collect::<Vec<_>>()];
+    for day in days {
+        for part in [1, 2] {
+            let mut row = vec![day.to_string(), part.to_string()];
+            for SetRun { set, runs } in sets {
+                let expected = manifest.and_then(|m| m.get(day, part, &set.file(day)));
+                let run = runs.iter().find(|run| run.day == day);
+                let cell = match run.map(|run| &run.parts[part as usize - 1]) {
+                    None => String::from("-"),
+                    Some(part) => match (&part.status, &part.answer, expected) {
+                        (Status::Ok, Some(answer), Some(expected))
+                            if answer.to_string() != expected =>
+                        {
+                            failed += 1;
+                            format!("FAIL {answer}")
+                        }
+                        (_, Some(answer), _) => answer.to_string(),
+                        (status, None, _) => {
+                            failed += usize::from(!status.is_ok());
+                            status.name().to_string()
+                        }
+                    },
+                };
+                row.push(cell);
+            }
+            rows.push(row);
+        }
+    }
+
+    let widths = (0..rows[0].len())
+        .map(|i| rows.iter().map(|row| row[i].len()).max().unwrap_or(0))
+        .collect::<Vec<_>>();
+    let table = rows
+        .iter()
+        .map(|row| {
+            let cells = row
+                .iter()
+                .zip(&widths)
+                .map(|(cell, &width)| format!("{cell:>width$}"))
+                .collect::<Vec<_>>();
+            cells.join("  ") + "\n"
+        })
+        .collect();
+    (table, failed)
+}
+
+fn json_string(s: &str) -> String {
+    let mut out = String::from('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{input::InputSet, runner::PartRun};
+    use std::path::PathBuf;
+
+    fn runs() -> Vec<DayRun> {
+        vec![DayRun {
+            day: 25,
+            parse: Duration::from_nanos(10),
+            parse_memory: None,
+            parts: [
+                PartRun {
+                    part: 1,
+                    answer: Some(Answer::Number(54)),
+                    elapsed: Duration::from_nanos(20),
+                    status: Status::Ok,
+                    memory: Some(Stats {
+                        allocations: 2,
+                        bytes: 64,
+                        peak: 32,
+                    }),
+                },
+                PartRun {
+                    part: 2,
+                    answer: None,
+                    elapsed: Duration::ZERO,
+                    status: Status::Error(String::from("bad \"input\", line 1")),
+                    memory: None,
+                },
+            ],
+        }]
+    }
+
+    #[test]
+    fn test_json_lines() {
+        assert_eq!(
+            render(&runs(), Format::JsonLines),
+            concat!(
+                r#"{"day":25,"part":1,"answer":"54","parse_ns":10,"time_ns":20,"status":"ok","error":null,"allocations":2,"bytes":64,"peak_bytes":32}"#,
+                "\n",
+                r#"{"day":25,"part":2,"answer":null,"parse_ns":10,"time_ns":0,"status":"error","error":"bad \"input\", line 1","allocations":null,"bytes":null,"peak_bytes":null}"#,
+                "\n"
+            )
+        );
+    }
+
+    #[test]
+    fn test_sets() {
+        let set = |name: &str| InputSet {
+            name: name.to_string(),
+            dir: PathBuf::from(name),
+        };
+        let mut other = runs();
+        other[0].parts[0].answer = Some(Answer::Number(55));
+        let sets = vec![
+            SetRun {
+                set: set("default"),
+                runs: runs(),
+            },
+            SetRun {
+                set: set("alice"),
+                runs: other,
+            },
+        ];
+        let manifest = "day,part,input,answer\n25,1,alice/day-25.txt,54"
+            .parse::<Manifest>()
+            .unwrap();
+
+        let (table, failed) = summary(&sets, Some(&manifest));
+        assert_eq!(
+            table,
+            concat!(
+                "day  part  default    alice\n",
+                " 25     1       54  FAIL 55\n",
+                " 25     2    error    error\n",
+            )
+        );
+        assert_eq!(failed, 3);
+        assert_eq!(
+            render_sets(&sets, Format::Csv).lines().nth(3),
+            Some("alice,25,1,55,10,20,ok,,2,64,32")
+        );
+        assert!(render_sets(&sets, Format::JsonLines).starts_with(r#"{"set":"default","day":25,"#));
+    }
+
+    #[test]
+    fn test_csv() {
+        assert_eq!(
+            render(&runs(), Format::Csv),
+            concat!(
+                "day,part,answer,parse_ns,time_ns,status,error,allocations,bytes,peak_bytes\n",
+                "25,1,54,10,20,ok,,2,64,32\n",
+                "25,2,,10,0,error,\"bad \"\"input\"\", line 1\",,,\n"
+            )
+        );
+    }
+}
@@ -0,0 +1,419 @@
+use std::{
+    any::Any,
+    fmt::Display,
+    panic::{self, AssertUnwindSafe},
+    process,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc, Mutex,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    bench::{self, Config},
+    input::{self, InputSet, Source},
+    memory::{self, Stats},
+    solution::{Answer, Entry, Registry, Solution},
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    Ok,
+    // the part was not asked for, e.g. an example that only applies to the other part
+    Skipped,
+    Error(String),
+    Panicked(String),
+    TimedOut,
+}
+
+impl Status {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Status::Ok => "ok",
+            Status::Skipped => "skipped",
+            Status::Error(_) => "error",
+            Status::Panicked(_) => "panicked",
+            Status::TimedOut => "timeout",
+        }
+    }
+
+    pub fn is_ok(&self) -> bool {
+        matches!(self, Status::Ok | Status::Skipped)
+    }
+
+    pub fn message(&self) -> Option<&str> {
+        match self {
+            Status::Ok | Status::Skipped => None,
+            Status::Error(message) | Status::Panicked(message) => Some(message),
+            Status::TimedOut => Some("timed out"),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PartRun {
+    pub part: u8,
+    pub answer: Option<Answer>,
+    pub elapsed: Duration,
+    // errors are kept as text so runs can be sent across threads
+    pub status: Status,
+    // only with the `alloc-stats` feature
+    pub memory: Option<Stats>,
+}
+
+impl PartRun {
+    fn new(
+        part: u8,
+        result: Result<Answer, String>,
+        elapsed: Duration,
+        memory: Option<Stats>,
+    ) -> Self {
+        match result {
+            Ok(answer) => Self {
+                part,
+                answer: Some(answer),
+                elapsed,
+                status: Status::Ok,
+                memory,
+            },
+            Err(e) => Self::failed(part, Status::Error(e)),
+        }
+    }
+
+    fn failed(part: u8, status: Status) -> Self {
+        Self {
+            part,
+            answer: None,
+            elapsed: Duration::ZERO,
+            status,
+            memory: None,
+        }
+    }
+
+    fn skipped(part: u8) -> Self {
+        Self {
+            part,
+            answer: None,
+            elapsed: Duration::ZERO,
+            status: Status::Skipped,
+            memory: None,
+        }
+    }
+}
+
+/// Outcome of running one day in-process.
+#[derive(Debug, Clone)]
+pub struct DayRun {
+    pub day: u8,
+    pub parse: Duration,
+    pub parse_memory: Option<Stats>,
+    pub parts: [PartRun; 2],
+}
+
+impl DayRun {
+    fn failed(day: u8, status: Status) -> Self {
+        Self {
+            day,
+            parse: Duration::ZERO,
+            parse_memory: None,
+            parts: [
+                PartRun::failed(1, status.clone()),
+                PartRun::failed(2, status),
+            ],
+        }
+    }
+
+    pub fn elapsed(&self) -> Duration {
+        self.parse + self.parts.iter().map(|part| part.elapsed).sum::<Duration>()
+    }
+
+    pub fn is_ok(&self) -> bool {
+        self.parts.iter().all(|part| part.status.is_ok())
+    }
+}
+
+impl Display for DayRun {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "day-{}:", self.day)?;
+        for (name, part) in ["one", "two"].iter().zip(&self.parts) {
+            match (&part.answer, &part.status) {
+                (Some(answer), _) => match part.memory {
+                    Some(memory) => writeln!(f, "Part {name}: {answer} ({memory})")?,
+                    None => writeln!(f, "Part {name}: {answer}")?,
+                },
+                (None, Status::Skipped) => writeln!(f, "Part {name}: skipped")?,
+                (None, Status::Panicked(message)) => writeln!(f, "Part {name}: PANIC: {message}")?,
+                (None, status) => writeln!(
+                    f,
+                    "Part {name}: ERROR: {}",
+                    status.message().unwrap_or_default()
+                )?,
+            }
+        }
+        if let Some(memory) = self.parse_memory {
+            writeln!(f, "Parsing: {memory}")?;
+        }
+        write!(f, "Time: {:.3} ms", self.elapsed().as_secs_f64() * 1_000.0)
+    }
+}
+
+/// Number of days that did not finish both parts, panics and timeouts included.
+pub fn failures(runs: &[DayRun]) -> usize {
+    runs.iter().filter(|run| !run.is_ok()).count()
+}
+
+pub fn run_day(entry: &Entry, source: &Source) -> DayRun {
+    match input::load(entry.day, source) {
+        Ok(raw) => run_input(entry, &raw),
+        Err(e) => DayRun::failed(entry.day, Status::Error(e.to_string())),
+    }
+}
+
+// Like `run_day`, but gives up on the day once `timeout` is spent. The day
+// runs on its own thread, which cannot be stopped: a day that timed out keeps
+// running in the background until the process exits.
+pub fn run_day_with_timeout(entry: &Entry, source: &Source, timeout: Option<Duration>) -> DayRun {
+    let Some(timeout) = timeout else {
+        return run_day(entry, source);
+    };
+    let (sender, receiver) = mpsc::channel();
+    let (entry, source) = (*entry, source.clone());
+    thread::spawn(move || {
+        // nobody is listening any more if the day timed out
+        let _ = sender.send(run_day(&entry, &source));
+    });
+    match receiver.recv_timeout(timeout) {
+        Ok(run) => run,
+        Err(mpsc::RecvTimeoutError::Timeout) => DayRun::failed(entry.day, Status::TimedOut),
+        Err(mpsc::RecvTimeoutError::Disconnected) => DayRun::failed(
+            entry.day,
+            Status::Panicked(String::from("the day stopped without a result")),
+        ),
+    }
+}
+
+pub fn run_input(entry: &Entry, raw: &str) -> DayRun {
+    run_parts(entry, raw, [true, true])
+}
+
+// Like `run_input` but only runs the parts set in `parts`, the others are
+// reported as skipped.
+pub fn run_parts(entry: &Entry, raw: &str, parts: [bool; 2]) -> DayRun {
+    let now = Instant::now();
+    let (parsed, parse_memory) = memory::measure(|| panic::catch_unwind(|| (entry.parse)(raw)));
+    let parsed = match parsed {
+        Ok(Ok(parsed)) => parsed,
+        Ok(Err(e)) => return DayRun::failed(entry.day, Status::Error(e.to_string())),
+        Err(payload) => return DayRun::failed(entry.day, Status::Panicked(panic_message(payload))),
+    };
+    let parse = now.elapsed();
+
+    let run_part = |part: u8| {
+        if !parts[part as usize - 1] {
+            return PartRun::skipped(part);
+        }
+        let now = Instant::now();
+        let (answer, memory) = memory::measure(|| {
+            panic::catch_unwind(AssertUnwindSafe(|| match part {
+                1 => parsed.part_1(),
+                _ => parsed.part_2(),
+            }))
+        });
+        let elapsed = now.elapsed();
+        match answer {
+            Ok(answer) => PartRun::new(part, answer.map_err(|e| e.to_string()), elapsed, memory),
+            Err(payload) => PartRun::failed(part, Status::Panicked(panic_message(payload))),
+        }
+    };
+    DayRun {
+        day: entry.day,
+        parse,
+        parse_memory,
+        parts: [run_part(1), run_part(2)],
+    }
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => payload.downcast_ref::<&str>().map_or_else(
+            || String::from("unknown panic"),
+            |message| message.to_string(),
+        ),
+    }
+}
+
+// Run every registered day on its default input. With more than one thread the
+// days are shared between scoped workers, the results still come back in day
+// order. A day that panics or outlives `timeout` is reported as such, the other
+// days still run.
+pub fn run_all(registry: &Registry, threads: usize, timeout: Option<Duration>) -> Vec<DayRun> {
+    run_sources(registry, threads, timeout, |_| Some(Source::Default))
+}
+
+// Like `run_all`, but each day reads the input given by `source`. Days without
+// one are left out.
+fn run_sources(
+    registry: &Registry,
+    threads: usize,
+    timeout: Option<Duration>,
+    source: impl Fn(u8) -> Option<Source> + Sync,
+) -> Vec<DayRun> {
+    let entries = registry
+        .entries()
+        .iter()
+        .filter_map(|entry| Some((entry, source(entry.day)?)))
+        .collect::<Vec<_>>();
+    if threads <= 1 {
+        return entries
+            .iter()
+            .map(|(entry, source)| run_day_with_timeout(entry, source, timeout))
+            .collect();
+    }
+
+    let next = AtomicUsize::new(0);
+    let runs = Mutex::new(Vec::with_capacity(entries.len()));
+    thread::scope(|scope| {
+        for _ in 0..threads.min(entries.len()) {
+            scope.spawn(|| {
+                while let Some((entry, source)) = entries.get(next.fetch_add(1, Ordering::Relaxed))
+                {
+                    let run = run_day_with_timeout(entry, source, timeout);
+                    runs.lock().unwrap().push(run);
+                }
+            });
+        }
+    });
+    let mut runs = runs.into_inner().unwrap();
+    runs.sort_by_key(|run| run.day);
+    runs
+}
+
+/// Outcome of running every day on one input set.
+#[derive(Debug, Clone)]
+pub struct SetRun {
+    pub set: InputSet,
+    // only the days the set has an input for
+    pub runs: Vec<DayRun>,
+}
+
+// Run every registered day on every set it has an input in, one set after the
+// other.
+pub fn run_sets(
+    registry: &Registry,
+    sets: &[InputSet],
+    threads: usize,
+    timeout: Option<Duration>,
+) -> Vec<SetRun> {
+    sets.iter()
+        .map(|set| SetRun {
+            set: set.clone(),
+            runs: run_sources(registry, threads, timeout, |day| set.source(day)),
+        })
+        .collect()
+}
+
+// Entry point of the `day-N` binaries, see `main!`. Takes an optional
+// `--bench` flag followed by the input (a path, `-` for stdin or nothing).
+pub fn run_binary<S: Solution + 'static>() {
+    let mut args = std::env::args().skip(1).peekable();
+    let benchmark = args.next_if(|arg| arg == "--bench").is_some();
+    let source = Source::from_arg(args.next().as_deref());
+    let entry = Entry::of::<S>();
+
+    let result = input::load(entry.day, &source)
+        .map_err(|e| e.to_string())
+        .and_then(|raw| {
+            if benchmark {
+                let bench = bench::bench(&entry, &raw, &Config::default());
+                bench
+                    .map(|bench| println!("{bench}"))
+                    .map_err(|e| e.to_string())
+            } else {
+                let run = run_input(&entry, &raw);
+                println!("{run}");
+                match run.is_ok() {
+                    true => Ok(()),
+                    false => Err(format!("day {} failed", run.day)),
+                }
+            }
+        });
+    if let Err(e) = result {
+        eprintln!("ERROR: {e}");
+        process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Result;
+
+    struct Panics;
+
+    impl Solution for Panics {
+        const DAY: u8 = 1;
+        type Input = Vec<u32>;
+        type Part1 = u32;
+        type Part2 = u32;
+
+        fn parse(input: &str) -> Result<Self::Input> {
+            Ok(input.lines().map(|line| line.parse().unwrap()).collect())
+        }
+
+        fn part_1(input: &Self::Input) -> Result<u32> {
+            Ok(input.iter().sum())
+        }
+
+        fn part_2(input: &Self::Input) -> Result<u32> {
+            Ok(input[10])
+        }
+    }
+
+    struct Sleeps;
+
+    impl Solution for Sleeps {
+        const DAY: u8 = 2;
+        type Input = ();
+        type Part1 = u32;
+        type Part2 = u32;
+
+        fn parse(_: &str) -> Result<Self::Input> {
+            thread::sleep(Duration::from_secs(5));
+            Ok(())
+        }
+
+        fn part_1(_: &Self::Input) -> Result<u32> {
+            Ok(1)
+        }
+
+        fn part_2(_: &Self::Input) -> Result<u32> {
+            Ok(2)
+        }
+    }
+
+    #[test]
+    fn test_panics() {
+        let entry = Entry::of::<Panics>();
+        let run = run_input(&entry, "1\n2");
+        assert_eq!(run.parts[0].answer, Some(Answer::Number(3)));
+        assert!(matches!(run.parts[1].status, Status::Panicked(_)));
+
+        let run = run_input(&entry, "1\nx");
+        assert!(run
+            .parts
+            .iter()
+            .all(|part| part.status.name() == "panicked"));
+    }
+
+    #[test]
+    fn test_timeout() {
+        let mut registry = Registry::new();
+        registry.register::<Sleeps>();
+        let runs = run_all(&registry, 1, Some(Duration::from_millis(50)));
+        assert_eq!(runs[0].parts[0].status, Status::TimedOut);
+        assert_eq!(failures(&runs), 1);
+    }
+}
@@ -0,0 +1,346 @@
+use std::{
+    fs::{self, OpenOptions},
+    io::Write,
+    path::{Path, PathBuf},
+};
+
+use crate::{err, Error, Result};
+
+const SOLUTION: &str = "//! Day {N}
+
+use crate::{solution::Solution, Result};
+
+pub struct Day{NN};
+
+impl Solution for Day{NN} {
+    const DAY: u8 = {N};
+    type Input = Vec<String>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<String>> {
+        Ok(input.lines().map(String::from).collect())
+    }
+
+    fn part_1(_: &Vec<String>) -> Result<usize> {
+        Ok(0)
+    }
+
+    fn part_2(_: &Vec<String>) -> Result<usize> {
+        Ok(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    const TEST_INPUT: &str = include_str!(\"../inputs/day-{N}-test.txt\");
+    #[test]
+    fn test_input() {
+        // the answers of the example in the puzzle text
+        assert_eq!(Day{NN}::solve_part_1(TEST_INPUT).unwrap(), 0);
+        assert_eq!(Day{NN}::solve_part_2(TEST_INPUT).unwrap(), 0);
+    }
+}
+";
+
+const BINARY: &str = "use aoc_2023_lib::{main, Day{NN}};
+
+main!(Day{NN});
+";
+
+const ANSWERS: &str = "# day {N}, uncomment once the answers are known
+# {N},1,day-{N}-test.txt,
+# {N},2,day-{N}-test.txt,
+# {N},1,day-{N}.txt,
+# {N},2,day-{N}.txt,
+";
+
+fn render(template: &str, day: u8) -> String {
+    template
+        .replace("{NN}", &format!("{day:02}"))
+        .replace("{N}", &day.to_string())
+}
+
+// Like `fs::write`, but fails instead of overwriting an existing file.
+fn create(path: &Path, contents: &str) -> Result<()> {
+    OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(path)
+        .and_then(|mut file| file.write_all(contents.as_bytes()))
+        .map_err(|e| Error::io("create", path, e))
+}
+
+// Add `line` to the run of lines accepted by `is_group`, keeping them sorted.
+// The last line of the run keeps its `;`, if it had one.
+fn insert_sorted(
+    lines: &mut Vec<String>,
+    is_group: impl Fn(&str) -> bool,
+    line: String,
+) -> Result<()> {
+    let group = lines
+        .iter()
+        .enumerate()
+        .filter(|(_, l)| is_group(l))
+        .map(|(i, _)| i)
+        .collect::<Vec<_>>();
+    let (Some(&first), Some(&last)) = (group.first(), group.last()) else {
+        return err!(
+            Assumption,
+            "cannot find where to add `{}` in lib.rs",
+            line.trim()
+        );
+    };
+    let terminated = lines[last].ends_with(';') && !line.ends_with(';');
+    if terminated {
+        lines[last].pop();
+    }
+    let at = (first..=last)
+        .find(|&i| lines[i].as_str() > line.as_str())
+        .unwrap_or(last + 1);
+    lines.insert(at, line);
+    if terminated {
+        lines[last + 1].push(';');
+    }
+    Ok(())
+}
+
+// Declare, export and register the day in `lib.rs`.
+fn declare(lib: &str, day: u8) -> Result<String> {
+    let module = format!("day{day:02}");
+    if lib.lines().any(|line| line == format!("pub mod {module};")) {
+        return err!(Usage, "{module} is already declared in lib.rs");
+    }
+    let mut lines = lib.lines().map(String::from).collect::<Vec<_>>();
+    insert_sorted(
+        &mut lines,
+        |l| l.starts_with("pub mod day"),
+        format!("pub mod {module};"),
+    )?;
+    insert_sorted(
+        &mut lines,
+        |l| l.starts_with("pub use day"),
+        format!("pub use {module}::Day{day:02};"),
+    )?;
+    insert_sorted(
+        &mut lines,
+        |l| l.trim_start().starts_with(".register::<"),
+        format!("        .register::<Day{day:02}>()"),
+    )?;
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Generate day `day` in the repository at `root`: the solution with its
+/// tests, the binary, empty inputs and commented-out manifest entries. Existing
+/// files are never overwritten, inputs that are already there are kept, and
+/// nothing is left behind if any file fails to be written. Returns the files
+/// written.
+pub fn new_day(root: &Path, day: u8) -> Result<Vec<PathBuf>> {
+    if !(1..=25).contains(&day) {
+        return err!(Usage, "there is no day {day} in the calendar");
+    }
+    let solution = root.join("src").join(format!("day{day:02}.rs"));
+    let binary = root.join("src").join("bin").join(format!("day-{day}.rs"));
+    let lib = root.join("src").join("lib.rs");
+    let answers = root.join("inputs").join("answers.csv");
+
+    // check and render everything before writing anything
+    for path in [&solution, &binary] {
+        if path.exists() {
+            return err!(Usage, "{} already exists", path.display());
+        }
+    }
+    let lib_source = fs::read_to_string(&lib).map_err(|e| Error::io("read", &lib, e))?;
+    let declared = declare(&lib_source, day)?;
+    let original = match answers.exists() {
+        true => Some(fs::read_to_string(&answers).map_err(|e| Error::io("read", &answers, e))?),
+        false => None,
+    };
+    let mut manifest = original.clone().unwrap_or_default();
+    // never glue the new rows onto the last one
+    if !manifest.is_empty() && !manifest.ends_with('\n') {
+        manifest.push('\n');
+    }
+    manifest.push_str(&render(ANSWERS, day));
+
+    let mut new = vec![
+        (solution, render(SOLUTION, day)),
+        (binary, render(BINARY, day)),
+    ];
+    for input in [format!("day-{day}.txt"), format!("day-{day}-test.txt")] {
+        let path = root.join("inputs").join(input);
+        if !path.exists() {
+            new.push((path, String::new()));
+        }
+    }
+    // lib.rs last, a manifest with extra comments is harmless if that fails
+    let replaced = [
+        (answers, manifest, original),
+        (lib, declared, Some(lib_source)),
+    ];
+
+    let mut undo = Vec::new();
+    if let Err(e) = write_files(&new, &replaced, &mut undo, |from, to| fs::rename(from, to)) {
+        // leave no half generated day behind
+        roll_back(undo);
+        return Err(e);
+    }
+    Ok(new
+        .into_iter()
+        .map(|(path, _)| path)
+        .chain(replaced.into_iter().map(|(path, ..)| path))
+        .collect())
+}
+
+// How to take back a step of `write_files`.
+enum Undo {
+    Remove(PathBuf),
+    Restore(PathBuf, String),
+}
+
+// Create the `new` files, then replace the `replaced` ones, given with their
+// contents before, by writing them next to the originals and renaming them
+// over only once all are written. Every step taken is added to `undo`, for the
+// caller to roll back if this fails.
+fn write_files(
+    new: &[(PathBuf, String)],
+    replaced: &[(PathBuf, String, Option<String>)],
+    undo: &mut Vec<Undo>,
+    mut rename: impl FnMut(&Path, &Path) -> std::io::Result<()>,
+) -> Result<()> {
+    for (path, contents) in new {
+        create(path, contents)?;
+        undo.push(Undo::Remove(path.clone()));
+    }
+    let mut staged = Vec::new();
+    for (path, contents, original) in replaced {
+        let mut temporary = path.clone().into_os_string();
+        temporary.push(".new");
+        let temporary = PathBuf::from(temporary);
+        create(&temporary, contents)?;
+        undo.push(Undo::Remove(temporary.clone()));
+        staged.push((temporary, path, original));
+    }
+    for (temporary, path, original) in staged {
+        rename(&temporary, path).map_err(|e| Error::io("replace", path, e))?;
+        undo.push(match original {
+            Some(original) => Undo::Restore(path.clone(), original.clone()),
+            None => Undo::Remove(path.clone()),
+        });
+    }
+    Ok(())
+}
+
+// Undo the steps of `write_files`, latest first, as well as can be.
+fn roll_back(undo: Vec<Undo>) {
+    for step in undo.into_iter().rev() {
+        let _ = match step {
+            Undo::Remove(path) => fs::remove_file(path),
+            Undo::Restore(path, contents) => fs::write(path, contents),
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_declare() {
+        let lib = "pub mod day01;\npub mod day03;\n\npub use day01::Day01;\npub use day03::Day03;\n\nfn registry() {\n    registry\n        .register::<Day01>()\n        .register::<Day03>();\n}\n";
+        assert_eq!(
+            declare(lib, 2).unwrap(),
+            "pub mod day01;\npub mod day02;\npub mod day03;\n\npub use day01::Day01;\npub use day02::Day02;\npub use day03::Day03;\n\nfn registry() {\n    registry\n        .register::<Day01>()\n        .register::<Day02>()\n        .register::<Day03>();\n}\n"
+        );
+        assert_eq!(
+            declare(lib, 4).unwrap().lines().rev().nth(1),
+            Some("        .register::<Day04>();")
+        );
+        assert!(declare(lib, 3).is_err());
+    }
+
+    #[test]
+    fn test_new_day() {
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        fs::create_dir_all(root.join("src").join("bin")).unwrap();
+        fs::create_dir_all(root.join("inputs")).unwrap();
+        fs::write(
+            root.join("src").join("lib.rs"),
+            "pub mod day01;\npub use day01::Day01;\n        .register::<Day01>();\n",
+        )
+        .unwrap();
+        fs::write(root.join("inputs").join("day-2.txt"), "my input").unwrap();
+
+        let written = new_day(&root, 2).unwrap();
+        assert_eq!(written.len(), 5);
+        let solution = fs::read_to_string(root.join("src").join("day02.rs")).unwrap();
+        assert!(solution.contains("impl Solution for Day02"));
+        assert!(solution.contains("../inputs/day-2-test.txt"));
+        assert_eq!(
+            fs::read_to_string(root.join("inputs").join("day-2.txt")).unwrap(),
+            "my input"
+        );
+        // a second run must not touch anything
+        assert!(new_day(&root, 2).is_err());
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_new_day_rollback() {
+        let root =
+            std::env::temp_dir().join(format!("aoc-scaffold-rollback-{}", std::process::id()));
+        fs::create_dir_all(root.join("src")).unwrap();
+        fs::create_dir_all(root.join("inputs")).unwrap();
+        let lib = "pub mod day01;\npub use day01::Day01;\n        .register::<Day01>();\n";
+        fs::write(root.join("src").join("lib.rs"), lib).unwrap();
+        let answers = root.join("inputs").join("answers.csv");
+        fs::write(&answers, "day,part,input,answer\n1,1,day-1.txt,42").unwrap();
+
+        // without src/bin the binary cannot be created, after the solution was
+        assert!(new_day(&root, 2).is_err());
+        assert!(!root.join("src").join("day02.rs").exists());
+        assert_eq!(
+            fs::read_to_string(root.join("src").join("lib.rs")).unwrap(),
+            lib
+        );
+        assert_eq!(fs::read_dir(root.join("inputs")).unwrap().count(), 1);
+
+        fs::create_dir_all(root.join("src").join("bin")).unwrap();
+        new_day(&root, 2).unwrap();
+        let manifest = fs::read_to_string(&answers).unwrap();
+        assert!(manifest.starts_with("day,part,input,answer\n1,1,day-1.txt,42\n# day 2"));
+
+        // a failure after the first file was replaced puts it back
+        let lib = root.join("src").join("lib.rs");
+        let before = [&answers, &lib].map(|path| fs::read_to_string(path).unwrap());
+        let replaced = [
+            (
+                answers.clone(),
+                String::from("new"),
+                Some(before[0].clone()),
+            ),
+            (lib.clone(), String::from("new"), Some(before[1].clone())),
+        ];
+        let new = [(root.join("src").join("day03.rs"), String::new())];
+        let mut undo = Vec::new();
+        let mut renames = 0;
+        let written = write_files(&new, &replaced, &mut undo, |from, to| {
+            renames += 1;
+            match renames {
+                1 => fs::rename(from, to),
+                _ => Err(std::io::ErrorKind::Other.into()),
+            }
+        });
+        assert!(written.is_err());
+        assert_eq!(fs::read_to_string(&answers).unwrap(), "new");
+        roll_back(undo);
+        assert_eq!(
+            [&answers, &lib].map(|path| fs::read_to_string(path).unwrap()),
+            before
+        );
+        assert!(!root.join("src").join("day03.rs").exists());
+        assert!(!root.join("src").join("lib.rs.new").exists());
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,136 @@
+use std::fmt::Display;
+
+use crate::{input, Result};
+
+/// A day of the calendar. Parsing is split from both parts so the same parsed
+/// input can be shared between them (and timed on its own). `parse` is always
+/// handed a normalised input, see `input::normalise`.
+pub trait Solution {
+    const DAY: u8;
+    type Input;
+    type Part1: Into<Answer>;
+    type Part2: Into<Answer>;
+
+    fn parse(input: &str) -> Result<Self::Input>;
+    fn part_1(input: &Self::Input) -> Result<Self::Part1>;
+    fn part_2(input: &Self::Input) -> Result<Self::Part2>;
+
+    fn solve_part_1(input: &str) -> Result<Self::Part1> {
+        Self::part_1(&Self::parse(&input::normalise(input))?)
+    }
+
+    fn solve_part_2(input: &str) -> Result<Self::Part2> {
+        Self::part_2(&Self::parse(&input::normalise(input))?)
+    }
+}
+
+/// The answer of one part, whatever type the day happens to produce.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Number(i128),
+    Text(String),
+}
+
+macro_rules! answer_from_number {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(value: $t) -> Self {
+                Answer::Number(value as i128)
+            }
+        })*
+    };
+}
+
+answer_from_number!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, i128);
+
+impl From<char> for Answer {
+    fn from(value: char) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{n}"),
+            Answer::Text(s) => write!(f, "{s}"),
+        }
+    }
+}
+
+/// A parsed input whose solution type has been erased, so days with
+/// different input types can live in the same registry.
+pub trait Parsed {
+    fn part_1(&self) -> Result<Answer>;
+    fn part_2(&self) -> Result<Answer>;
+}
+
+struct Prepared<S: Solution>(S::Input);
+
+impl<S: Solution> Parsed for Prepared<S> {
+    fn part_1(&self) -> Result<Answer> {
+        S::part_1(&self.0).map(Into::into)
+    }
+
+    fn part_2(&self) -> Result<Answer> {
+        S::part_2(&self.0).map(Into::into)
+    }
+}
+
+fn prepare<S: Solution + 'static>(input: &str) -> Result<Box<dyn Parsed>> {
+    Ok(Box::new(Prepared::<S>(S::parse(&input::normalise(input))?)))
+}
+
+#[derive(Clone, Copy)]
+pub struct Entry {
+    pub day: u8,
+    pub parse: fn(&str) -> Result<Box<dyn Parsed>>,
+}
+
+impl Entry {
+    pub fn of<S: Solution + 'static>() -> Self {
+        Self {
+            day: S::DAY,
+            parse: prepare::<S>,
+        }
+    }
+}
+
+/// Maps day numbers to their solutions.
+#[derive(Clone, Default)]
+pub struct Registry {
+    entries: Vec<Entry>,
+}
+
+impl Registry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn register<S: Solution + 'static>(&mut self) -> &mut Self {
+        self.entries.retain(|entry| entry.day != S::DAY);
+        self.entries.push(Entry::of::<S>());
+        self.entries.sort_by_key(|entry| entry.day);
+        self
+    }
+
+    pub fn get(&self, day: u8) -> Option<&Entry> {
+        self.entries.iter().find(|entry| entry.day == day)
+    }
+
+    pub fn entries(&self) -> &[Entry] {
+        &self.entries
+    }
+}
@@ -0,0 +1,127 @@
+//! Cycle detection in sequences `x0, f(x0), f(f(x0)), ...` that eventually
+//! repeat. They are described by `(mu, lambda)`: the first `mu` states come
+//! once, then the next `lambda` states repeat forever.
+
+use std::{collections::HashMap, hash::Hash};
+
+/// Brent's algorithm, keeping only two states around at once. Good for small
+/// states, or when there is no cheap fingerprint to hash.
+pub fn brent<T: Clone + PartialEq>(x0: T, mut f: impl FnMut(&T) -> T) -> (usize, usize) {
+    // find lambda, looking for the hare in windows of growing powers of two
+    let (mut power, mut lambda) = (1, 1);
+    let mut tortoise = x0.clone();
+    let mut hare = f(&x0);
+    while tortoise != hare {
+        if power == lambda {
+            tortoise = hare.clone();
+            power *= 2;
+            lambda = 0;
+        }
+        hare = f(&hare);
+        lambda += 1;
+    }
+
+    // then mu, with the hare lambda steps ahead of the tortoise
+    let mut tortoise = x0.clone();
+    let mut hare = x0;
+    for _ in 0..lambda {
+        hare = f(&hare);
+    }
+    let mut mu = 0;
+    while tortoise != hare {
+        tortoise = f(&tortoise);
+        hare = f(&hare);
+        mu += 1;
+    }
+    (mu, lambda)
+}
+
+/// Step until the fingerprint `key` of a state repeats, remembering the
+/// fingerprint of every state seen. Calls `f` only `mu + lambda` times.
+pub fn hashed<T, K: Hash + Eq>(
+    x0: T,
+    f: impl FnMut(&T) -> T,
+    key: impl FnMut(&T) -> K,
+) -> (usize, usize) {
+    walk(x0, f, key, None).0
+}
+
+/// The index of step `n` within the first `mu + lambda` steps.
+pub fn reduce(n: usize, (mu, lambda): (usize, usize)) -> usize {
+    if n < mu {
+        n
+    } else {
+        mu + (n - mu) % lambda
+    }
+}
+
+/// The state after `n` steps, however big `n` is, remembering only the
+/// fingerprint of the states on the way: once one repeats, steps on from there
+/// to the same point of the cycle.
+pub fn nth<T, K: Hash + Eq>(
+    x0: T,
+    mut f: impl FnMut(&T) -> T,
+    key: impl FnMut(&T) -> K,
+    n: usize,
+) -> T {
+    let ((mu, lambda), mut state) = walk(x0, &mut f, key, Some(n));
+    if lambda == 0 {
+        // stopped at step n before repeating
+        return state;
+    }
+    // back at step mu
+    for _ in mu..reduce(n, (mu, lambda)) {
+        state = f(&state);
+    }
+    state
+}
+
+// Step until a fingerprint repeats, or until step `until`, and return the
+// state stopped at: `(mu, lambda)` and the first repeat, or `(until, 0)` and
+// the state at that step.
+fn walk<T, K: Hash + Eq>(
+    x0: T,
+    mut f: impl FnMut(&T) -> T,
+    mut key: impl FnMut(&T) -> K,
+    until: Option<usize>,
+) -> ((usize, usize), T) {
+    let mut seen = HashMap::new();
+    let mut state = x0;
+    let mut step = 0;
+    loop {
+        if let Some(first) = seen.insert(key(&state), step) {
+            return ((first, step - first), state);
+        }
+        if until == Some(step) {
+            return ((step, 0), state);
+        }
+        state = f(&state);
+        step += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 0, 1, 2, then 3, 4, 5, 6, 7 forever
+    fn next(x: &u32) -> u32 {
+        if *x < 7 {
+            x + 1
+        } else {
+            3
+        }
+    }
+
+    #[test]
+    fn test_cycle() {
+        assert_eq!(brent(0, next), (3, 5));
+        assert_eq!(hashed(0, next, |&x| x), (3, 5));
+        assert_eq!(brent(5, next), (0, 5));
+        assert_eq!(reduce(2, (3, 5)), 2);
+        assert_eq!(reduce(1_000_000_000, (3, 5)), 5);
+        assert_eq!(nth(0, next, |&x| x, 1_000_000_000), 5);
+        assert_eq!(nth(0, next, |&x| x, 4), 4);
+        assert_eq!(nth(0, next, |&x| x, 0), 0);
+    }
+}
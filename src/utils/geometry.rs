@@ -0,0 +1,200 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+use super::Pos;
+
+/// One of the four directions of a grid, `North` being towards the first row.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction {
+    /// Clockwise, starting north.
+    pub const ALL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    /// `U`/`D`/`L`/`R`, `N`/`S`/`E`/`W`, arrows (`^`, `v`, `<`, `>`), or the
+    /// digit codes of day 18 (`0` right, `1` down, `2` left, `3` up).
+    pub fn from_char(c: char) -> Option<Self> {
+        match c {
+            'U' | 'N' | '^' | '3' => Some(Direction::North),
+            'R' | 'E' | '>' | '0' => Some(Direction::East),
+            'D' | 'S' | 'v' | '1' => Some(Direction::South),
+            'L' | 'W' | '<' | '2' => Some(Direction::West),
+            _ => None,
+        }
+    }
+
+    pub fn opposite(self) -> Self {
+        self.turn_right().turn_right()
+    }
+
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn is_vertical(self) -> bool {
+        matches!(self, Direction::North | Direction::South)
+    }
+
+    pub fn is_horizontal(self) -> bool {
+        !self.is_vertical()
+    }
+
+    /// One step in this direction, with `y` growing southwards.
+    pub fn delta<T: From<i8>>(self) -> Point<T> {
+        let (x, y) = match self {
+            Direction::North => (0, -1),
+            Direction::East => (1, 0),
+            Direction::South => (0, 1),
+            Direction::West => (-1, 0),
+        };
+        Point::new(T::from(x), T::from(y))
+    }
+}
+
+/// A point or a vector on a plane.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Point<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+}
+
+// A grid position `(row, col)` is the point `(col, row)`.
+impl From<Pos> for Point<usize> {
+    fn from((row, col): Pos) -> Self {
+        Self::new(col, row)
+    }
+}
+
+impl From<Point<usize>> for Pos {
+    fn from(point: Point<usize>) -> Self {
+        (point.y, point.x)
+    }
+}
+
+impl Point<usize> {
+    /// The neighbour in `direction`, `None` past the top or the left edge.
+    pub fn step(self, direction: Direction) -> Option<Self> {
+        let delta = direction.delta::<isize>();
+        Some(Self::new(
+            self.x.checked_add_signed(delta.x)?,
+            self.y.checked_add_signed(delta.y)?,
+        ))
+    }
+}
+
+impl<T> Point<T>
+where
+    T: Copy + Sub<Output = T> + Add<Output = T> + PartialOrd,
+{
+    /// Distance along the axes.
+    pub fn manhattan(self, other: Self) -> T {
+        let dx = if self.x > other.x {
+            self.x - other.x
+        } else {
+            other.x - self.x
+        };
+        let dy = if self.y > other.y {
+            self.y - other.y
+        } else {
+            other.y - self.y
+        };
+        dx + dy
+    }
+}
+
+impl<T: Add<Output = T>> Add for Point<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl<T: Copy + Mul<Output = T>> Mul<T> for Point<T> {
+    type Output = Self;
+
+    fn mul(self, factor: T) -> Self {
+        Self::new(self.x * factor, self.y * factor)
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Point<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.x, -self.y)
+    }
+}
+
+impl<T: AddAssign> AddAssign for Point<T> {
+    fn add_assign(&mut self, other: Self) {
+        self.x += other.x;
+        self.y += other.y;
+    }
+}
+
+impl<T: SubAssign> SubAssign for Point<T> {
+    fn sub_assign(&mut self, other: Self) {
+        self.x -= other.x;
+        self.y -= other.y;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_direction() {
+        assert_eq!(Direction::from_char('U'), Some(Direction::North));
+        assert_eq!(Direction::from_char('0'), Some(Direction::East));
+        assert_eq!(Direction::from_char('x'), None);
+        assert_eq!(Direction::West.turn_right(), Direction::North);
+        assert_eq!(Direction::North.turn_left(), Direction::West);
+        assert_eq!(Direction::East.opposite(), Direction::West);
+        assert!(Direction::South.is_vertical());
+        assert_eq!(Direction::South.delta::<i64>(), Point::new(0, 1));
+    }
+
+    #[test]
+    fn test_point() {
+        let mut p = Point::new(1, 2) + Direction::West.delta() * 3;
+        assert_eq!(p, Point::new(-2, 2));
+        p -= Point::new(1, 1);
+        assert_eq!(-p, Point::new(3, -1));
+        assert_eq!(p.manhattan(Point::new(0, 0)), 4);
+        assert_eq!(Point::new(0usize, 3).step(Direction::West), None);
+        assert_eq!(
+            Point::new(0usize, 3).step(Direction::North),
+            Some(Point::new(0, 2))
+        );
+        assert_eq!(Pos::from(Point::new(1, 2)), (2, 1));
+    }
+}
@@ -0,0 +1,139 @@
+//! Mazes on a grid shrunk to graphs: the junctions become nodes and the
+//! corridors between them weighted edges, to search those instead of every
+//! tile.
+
+use std::collections::HashMap;
+
+use super::{Direction, Grid, Pos};
+
+/// A weighted directed graph over nodes numbered `0..len()`, each standing
+/// for a position of the grid it came from.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Graph {
+    positions: Vec<Pos>,
+    index: HashMap<Pos, usize>,
+    // for each node, the `(node, weight)` its edges lead to
+    edges: Vec<Vec<(usize, usize)>>,
+}
+
+impl Graph {
+    /// The graph of the tiles of `grid` where more or less than two
+    /// corridors meet, and of those in `keep`, e.g. the start and end. A
+    /// corridor is an edge, weighted by its length, in each direction it can
+    /// be walked from end to end.
+    ///
+    /// `can_move(from, direction, to)` says whether one step from `from` to
+    /// its neighbour `to` is allowed; it should hold for neither way between
+    /// a tile and a wall. Tiles joined one way only, like slopes, still count
+    /// as joined when finding the junctions.
+    pub fn compress<T>(
+        grid: &Grid<T>,
+        keep: &[Pos],
+        mut can_move: impl FnMut(Pos, Direction, Pos) -> bool,
+    ) -> Self {
+        // the neighbours of each tile it is joined to, either way
+        let mut joined = Grid::new(grid.width(), grid.height(), Vec::new());
+        for (pos, _) in grid.iter() {
+            for direction in Direction::ALL {
+                let Some(next) = grid.towards(pos, direction) else {
+                    continue;
+                };
+                if can_move(pos, direction, next) || can_move(next, direction.opposite(), pos) {
+                    joined[pos].push((direction, next));
+                }
+            }
+        }
+
+        let mut graph = Graph::default();
+        for (pos, neighbours) in joined.iter() {
+            if (!neighbours.is_empty() && neighbours.len() != 2) || keep.contains(&pos) {
+                graph.index.insert(pos, graph.positions.len());
+                graph.positions.push(pos);
+            }
+        }
+        graph.edges = vec![Vec::new(); graph.positions.len()];
+
+        for (from, &start) in graph.positions.iter().enumerate() {
+            'corridors: for &(direction, next) in &joined[start] {
+                if !can_move(start, direction, next) {
+                    continue;
+                }
+                let (mut prev, mut at, mut len) = (start, next, 1);
+                while !graph.index.contains_key(&at) {
+                    // not a node, so exactly one way on
+                    let Some(&(direction, next)) = joined[at].iter().find(|(_, n)| *n != prev)
+                    else {
+                        continue 'corridors;
+                    };
+                    if !can_move(at, direction, next) {
+                        continue 'corridors;
+                    }
+                    (prev, at, len) = (at, next, len + 1);
+                }
+                graph.edges[from].push((graph.index[&at], len));
+            }
+        }
+        graph
+    }
+
+    pub fn len(&self) -> usize {
+        self.positions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.positions.is_empty()
+    }
+
+    /// Where `node` is in the grid.
+    pub fn position(&self, node: usize) -> Pos {
+        self.positions[node]
+    }
+
+    /// The node at `pos`, if it is one.
+    pub fn node(&self, pos: Pos) -> Option<usize> {
+        self.index.get(&pos).copied()
+    }
+
+    /// The `(node, weight)` of each edge from `node`.
+    pub fn edges(&self, node: usize) -> &[(usize, usize)] {
+        &self.edges[node]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compress() {
+        let grid = Grid::parse(
+            "\
+#.###
+#...#
+#.#v#
+#...#
+###.#",
+            Some,
+        )
+        .unwrap();
+        let graph = Graph::compress(&grid, &[(0, 1), (4, 3)], |from, direction, to| {
+            grid[to] != '#'
+                && (grid[from] == '.' || Direction::from_char(grid[from]) == Some(direction))
+        });
+        // the ends and the two junctions of the loop
+        assert_eq!(graph.len(), 4);
+        let [start, top, bottom, end] =
+            [(0, 1), (1, 1), (3, 3), (4, 3)].map(|p| graph.node(p).unwrap());
+        assert_eq!(graph.position(end), (4, 3));
+        assert_eq!(graph.node((1, 2)), None);
+        assert_eq!(graph.edges(start), &[(top, 1)]);
+        // both sides of the loop go down, only the left one back up
+        let mut edges = graph.edges(top).to_vec();
+        edges.sort();
+        assert_eq!(edges, vec![(start, 1), (bottom, 4), (bottom, 4)]);
+        let mut edges = graph.edges(bottom).to_vec();
+        edges.sort();
+        assert_eq!(edges, vec![(top, 4), (end, 1)]);
+        assert_eq!(graph.edges(end), &[(bottom, 1)]);
+    }
+}
@@ -0,0 +1,362 @@
+use std::{
+    fmt::Display,
+    ops::{Index, IndexMut},
+};
+
+use super::{Direction, Point};
+use crate::{error, input, Error, Result};
+
+/// A position in a grid, as `(row, col)`.
+pub type Pos = (usize, usize);
+
+/// The offsets of the 4 orthogonal neighbours, as `(row, col)`.
+pub const DIRECTIONS_4: [(isize, isize); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+
+/// The offsets of the 8 neighbours, diagonals included, as `(row, col)`.
+pub const DIRECTIONS_8: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+/// A rectangular grid, stored row after row and indexed by `(row, col)`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// A `width` by `height` grid filled with `value`.
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// A grid from its rows, `None` if they are not all as long.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Self> {
+        let width = rows.first().map_or(0, Vec::len);
+        if rows.iter().any(|row| row.len() != width) {
+            return None;
+        }
+        Some(Self {
+            width,
+            height: rows.len(),
+            cells: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    /// Parse one cell per character of every line of `input`, a part of the
+    /// whole puzzle input `whole` which errors are reported against. `cell`
+    /// returns `None` for characters that cannot be part of the grid.
+    pub fn parse_within(
+        whole: &str,
+        input: &str,
+        mut cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self> {
+        let mut rows = Vec::new();
+        let width = input::lines(input)
+            .next()
+            .map_or(0, |line| line.chars().count());
+        for line in input::lines(input) {
+            if line.chars().count() != width {
+                return Err(Error::at(whole, line, format!("expected {width} tiles")));
+            }
+            for (i, c) in line.char_indices() {
+                rows.push(cell(c).ok_or_else(|| error::unexpected_char(whole, line, i))?);
+            }
+        }
+        Ok(Self {
+            width,
+            height: rows.len() / width.max(1),
+            cells: rows,
+        })
+    }
+
+    /// Like `parse_within`, when `input` is the whole puzzle input.
+    pub fn parse(input: &str, cell: impl FnMut(char) -> Option<T>) -> Result<Self> {
+        Self::parse_within(input, input, cell)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (row, col): Pos) -> bool {
+        row < self.height && col < self.width
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.contains(pos)
+            .then(|| &self.cells[pos.0 * self.width + pos.1])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        self.contains(pos)
+            .then(|| &mut self.cells[pos.0 * self.width + pos.1])
+    }
+
+    /// The cell at a signed position, as if the grid repeated forever in
+    /// every direction.
+    pub fn get_wrapping(&self, row: i64, col: i64) -> &T {
+        let row = row.rem_euclid(self.height as i64) as usize;
+        let col = col.rem_euclid(self.width as i64) as usize;
+        &self[(row, col)]
+    }
+
+    /// `pos` moved by `(dr, dc)`, if that is still in the grid.
+    pub fn step(&self, (row, col): Pos, (dr, dc): (isize, isize)) -> Option<Pos> {
+        let pos = (row.checked_add_signed(dr)?, col.checked_add_signed(dc)?);
+        self.contains(pos).then_some(pos)
+    }
+
+    /// The neighbour of `pos` in `direction`, if that is still in the grid.
+    pub fn towards(&self, pos: Pos, direction: Direction) -> Option<Pos> {
+        let delta = direction.delta::<isize>();
+        self.step(pos, (delta.y, delta.x))
+    }
+
+    /// The orthogonal neighbours of `pos` within the grid.
+    pub fn neighbours_4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        DIRECTIONS_4
+            .iter()
+            .filter_map(move |&delta| self.step(pos, delta))
+    }
+
+    /// The neighbours of `pos` within the grid, diagonals included.
+    pub fn neighbours_8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        DIRECTIONS_8
+            .iter()
+            .filter_map(move |&delta| self.step(pos, delta))
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> + '_ {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> + '_ {
+        self.cells[col..].iter().step_by(self.width.max(1))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T> + '_> + '_ {
+        (0..self.width).map(|col| self.column(col))
+    }
+
+    /// Every cell with its position, row after row.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> + '_ {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(i, cell)| ((i / self.width, i % self.width), cell))
+    }
+
+    /// The position of the first cell, row after row, matching `predicate`.
+    pub fn position(&self, predicate: impl FnMut(&T) -> bool) -> Option<Pos> {
+        let i = self.cells.iter().position(predicate)?;
+        Some((i / self.width, i % self.width))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    // A `width` by `height` grid whose cell at `pos` is `self[from(pos)]`.
+    fn remap(&self, width: usize, height: usize, from: impl Fn(Pos) -> Pos) -> Self
+    where
+        T: Clone,
+    {
+        let cells = (0..height)
+            .flat_map(|row| (0..width).map(move |col| (row, col)))
+            .map(|pos| self[from(pos)].clone())
+            .collect();
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Rows become columns.
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.remap(self.height, self.width, |(row, col)| (col, row))
+    }
+
+    /// Turned a quarter clockwise: the first column becomes the first row.
+    pub fn rotate_clockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        let height = self.height;
+        self.remap(self.height, self.width, |(row, col)| {
+            (height - 1 - col, row)
+        })
+    }
+
+    /// Turned a quarter counterclockwise: the first row becomes the first
+    /// column.
+    pub fn rotate_counterclockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        let width = self.width;
+        self.remap(self.height, self.width, |(row, col)| (col, width - 1 - row))
+    }
+
+    /// Mirrored left to right.
+    pub fn flip_horizontal(&self) -> Self
+    where
+        T: Clone,
+    {
+        let width = self.width;
+        self.remap(self.width, self.height, |(row, col)| (row, width - 1 - col))
+    }
+
+    /// Mirrored top to bottom.
+    pub fn flip_vertical(&self) -> Self
+    where
+        T: Clone,
+    {
+        let height = self.height;
+        self.remap(self.width, self.height, |(row, col)| {
+            (height - 1 - row, col)
+        })
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (row, col): Pos) -> &T {
+        assert!(
+            col < self.width,
+            "column {col} out of a grid {} wide",
+            self.width
+        );
+        &self.cells[row * self.width + col]
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, (row, col): Pos) -> &mut T {
+        assert!(
+            col < self.width,
+            "column {col} out of a grid {} wide",
+            self.width
+        );
+        &mut self.cells[row * self.width + col]
+    }
+}
+
+impl<T> Index<Point<usize>> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point<usize>) -> &T {
+        &self[Pos::from(point)]
+    }
+}
+
+impl<T> IndexMut<Point<usize>> for Grid<T> {
+    fn index_mut(&mut self, point: Point<usize>) -> &mut T {
+        &mut self[Pos::from(point)]
+    }
+}
+
+// One line per row, without separators, e.g. back to the puzzle input.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_grid() {
+        let grid = Grid::parse("abc\ndef", Some).unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(1, 0)], 'd');
+        assert_eq!(grid.get((2, 0)), None);
+        assert_eq!(grid.get_wrapping(-1, 4), &'e');
+        assert_eq!(grid.to_string(), "abc\ndef\n");
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf\n");
+        assert_eq!(grid.rotate_clockwise().to_string(), "da\neb\nfc\n");
+        assert_eq!(grid.rotate_counterclockwise().to_string(), "cf\nbe\nad\n");
+        assert_eq!(grid.flip_horizontal().to_string(), "cba\nfed\n");
+        assert_eq!(grid.flip_vertical().to_string(), "def\nabc\n");
+        assert_eq!(
+            grid.rotate_clockwise().rotate_counterclockwise(),
+            grid.clone()
+        );
+        assert_eq!(grid.column(1).collect::<String>(), "be");
+        assert_eq!(grid.position(|&c| c == 'e'), Some((1, 1)));
+        assert_eq!(
+            grid.neighbours_4((0, 0)).collect::<Vec<_>>(),
+            vec![(0, 1), (1, 0)]
+        );
+        assert_eq!(grid.neighbours_8((1, 1)).count(), 5);
+        assert_eq!(grid.towards((1, 2), Direction::North), Some((0, 2)));
+        assert_eq!(grid.towards((1, 2), Direction::East), None);
+        assert_eq!(grid[Point::new(2, 0)], 'c');
+        assert_eq!(
+            Grid::from_rows(vec![vec![1, 2], vec![3, 4]])
+                .unwrap()
+                .row(1),
+            &[3, 4]
+        );
+        assert!(Grid::from_rows(vec![vec![1, 2], vec![3]]).is_none());
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let input = "..#\n.x.\n..";
+        let cell = |c| match c {
+            '.' | '#' => Some(c),
+            _ => None,
+        };
+        assert_eq!(
+            Grid::parse(&input[..7], cell).unwrap_err().to_string(),
+            "line 2, col 2: unexpected 'x'"
+        );
+        assert_eq!(
+            Grid::parse(&input.replace('x', "#"), cell)
+                .unwrap_err()
+                .to_string(),
+            "line 3, col 1: expected 3 tiles"
+        );
+    }
+}
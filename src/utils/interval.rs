@@ -0,0 +1,276 @@
+//! Sets and maps of integers built from half-open intervals, to work on whole
+//! ranges of numbers at once instead of number by number.
+
+use std::fmt::Display;
+
+/// The integers `start..end`, empty when `end <= start`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Interval {
+    pub start: i64,
+    pub end: i64,
+}
+
+impl Interval {
+    pub const fn new(start: i64, end: i64) -> Self {
+        Self { start, end }
+    }
+
+    /// The `len` integers from `start`.
+    pub const fn with_len(start: i64, len: i64) -> Self {
+        Self::new(start, start + len)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.end <= self.start
+    }
+
+    pub fn len(&self) -> i64 {
+        (self.end - self.start).max(0)
+    }
+
+    pub fn contains(&self, x: i64) -> bool {
+        self.start <= x && x < self.end
+    }
+
+    /// The integers in both, possibly empty.
+    pub fn intersect(&self, other: &Interval) -> Interval {
+        Interval::new(self.start.max(other.start), self.end.min(other.end))
+    }
+
+    /// Every integer moved by `offset`, saturating at the ends of `i64`.
+    pub fn shift(&self, offset: i64) -> Interval {
+        Interval::new(
+            self.start.saturating_add(offset),
+            self.end.saturating_add(offset),
+        )
+    }
+}
+
+impl Display for Interval {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}..{}", self.start, self.end)
+    }
+}
+
+/// A set of integers, kept as sorted intervals that neither overlap nor touch.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct IntervalSet {
+    intervals: Vec<Interval>,
+}
+
+impl IntervalSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert(&mut self, interval: Interval) {
+        if interval.is_empty() {
+            return;
+        }
+        // the intervals before and after are untouched, the ones in between
+        // merge with the new one
+        let before = self.intervals.partition_point(|i| i.end < interval.start);
+        let after = self.intervals.partition_point(|i| i.start <= interval.end);
+        let merged = self.intervals[before..after]
+            .iter()
+            .fold(interval, |merged, i| {
+                Interval::new(merged.start.min(i.start), merged.end.max(i.end))
+            });
+        self.intervals.splice(before..after, [merged]);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// How many integers are in the set.
+    pub fn len(&self) -> i64 {
+        self.intervals.iter().map(Interval::len).sum()
+    }
+
+    pub fn contains(&self, x: i64) -> bool {
+        let i = self.intervals.partition_point(|i| i.end <= x);
+        self.intervals.get(i).is_some_and(|i| i.contains(x))
+    }
+
+    pub fn min(&self) -> Option<i64> {
+        self.intervals.first().map(|i| i.start)
+    }
+
+    pub fn intervals(&self) -> impl Iterator<Item = Interval> + '_ {
+        self.intervals.iter().copied()
+    }
+
+    pub fn union(&self, other: &IntervalSet) -> IntervalSet {
+        self.intervals().chain(other.intervals()).collect()
+    }
+
+    pub fn intersect(&self, other: &IntervalSet) -> IntervalSet {
+        self.intervals()
+            .flat_map(|a| other.intervals().map(move |b| a.intersect(&b)))
+            .collect()
+    }
+
+    pub fn difference(&self, other: &IntervalSet) -> IntervalSet {
+        self.intersect(&other.complement())
+    }
+
+    /// Every integer not in the set.
+    pub fn complement(&self) -> IntervalSet {
+        let mut start = i64::MIN;
+        let mut out = IntervalSet::new();
+        for interval in self.intervals() {
+            out.insert(Interval::new(start, interval.start));
+            start = interval.end;
+        }
+        out.insert(Interval::new(start, i64::MAX));
+        out
+    }
+}
+
+impl From<Interval> for IntervalSet {
+    fn from(interval: Interval) -> Self {
+        [interval].into_iter().collect()
+    }
+}
+
+impl FromIterator<Interval> for IntervalSet {
+    fn from_iter<I: IntoIterator<Item = Interval>>(iter: I) -> Self {
+        let mut set = IntervalSet::new();
+        for interval in iter {
+            set.insert(interval);
+        }
+        set
+    }
+}
+
+/// A piecewise-linear map of integers: each number of a piece is moved by the
+/// offset of the piece, numbers outside every piece stay where they are.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RangeMap {
+    // sorted and disjoint, without zero offsets
+    pieces: Vec<(Interval, i64)>,
+}
+
+impl RangeMap {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Move the numbers of `interval` by `offset`, except those an earlier
+    /// piece already moves.
+    pub fn insert(&mut self, interval: Interval, offset: i64) {
+        let mapped = self.pieces.iter().map(|&(i, _)| i).collect::<IntervalSet>();
+        for free in IntervalSet::from(interval).difference(&mapped).intervals() {
+            if offset != 0 {
+                let at = self.pieces.partition_point(|(i, _)| i.start < free.start);
+                self.pieces.insert(at, (free, offset));
+            }
+        }
+    }
+
+    pub fn get(&self, x: i64) -> i64 {
+        let i = self.pieces.partition_point(|(i, _)| i.end <= x);
+        match self.pieces.get(i) {
+            Some((interval, offset)) if interval.contains(x) => x + offset,
+            _ => x,
+        }
+    }
+
+    // The pieces and the gaps between them, covering every integer.
+    fn segments(&self) -> Vec<(Interval, i64)> {
+        let mut segments = Vec::new();
+        let mut start = i64::MIN;
+        for &(interval, offset) in &self.pieces {
+            segments.push((Interval::new(start, interval.start), 0));
+            segments.push((interval, offset));
+            start = interval.end;
+        }
+        segments.push((Interval::new(start, i64::MAX), 0));
+        segments.retain(|(interval, _)| !interval.is_empty());
+        segments
+    }
+
+    /// Where the numbers of `set` end up.
+    pub fn map_set(&self, set: &IntervalSet) -> IntervalSet {
+        self.segments()
+            .into_iter()
+            .flat_map(|(segment, offset)| {
+                set.intervals()
+                    .map(move |interval| interval.intersect(&segment).shift(offset))
+            })
+            .collect()
+    }
+
+    /// The map of `then` applied after `self`.
+    pub fn then(&self, then: &RangeMap) -> RangeMap {
+        let mut composed = RangeMap::new();
+        for (segment, offset) in self.segments() {
+            let image = segment.shift(offset);
+            for (next, next_offset) in then.segments() {
+                let part = image.intersect(&next);
+                if !part.is_empty() {
+                    composed.insert(part.shift(-offset), offset + next_offset);
+                }
+            }
+        }
+        composed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_interval_set() {
+        let a = IntervalSet::from_iter([Interval::new(0, 5), Interval::new(10, 15)]);
+        let b = IntervalSet::from_iter([Interval::new(3, 12), Interval::new(5, 6)]);
+        assert_eq!(
+            a.union(&b).intervals().collect::<Vec<_>>(),
+            vec![Interval::new(0, 15)]
+        );
+        assert_eq!(
+            a.intersect(&b).intervals().collect::<Vec<_>>(),
+            vec![Interval::new(3, 5), Interval::new(10, 12)]
+        );
+        assert_eq!(
+            a.difference(&b).intervals().collect::<Vec<_>>(),
+            vec![Interval::new(0, 3), Interval::new(12, 15)]
+        );
+        assert_eq!(a.len(), 10);
+        assert!(a.contains(14) && !a.contains(5));
+        assert_eq!(b.min(), Some(3));
+        assert_eq!(a.complement().complement(), a);
+    }
+
+    #[test]
+    fn test_range_map() {
+        let mut map = RangeMap::new();
+        map.insert(Interval::with_len(98, 2), -48);
+        map.insert(Interval::with_len(50, 48), 2);
+        // already moved by the first piece
+        map.insert(Interval::new(99, 120), 1);
+        assert_eq!(
+            [0, 50, 97, 98, 99, 100].map(|x| map.get(x)),
+            [0, 52, 99, 50, 51, 101]
+        );
+
+        let seeds = IntervalSet::from(Interval::with_len(95, 10));
+        assert_eq!(
+            map.map_set(&seeds).intervals().collect::<Vec<_>>(),
+            vec![
+                Interval::new(50, 52),
+                Interval::new(97, 100),
+                Interval::new(101, 106)
+            ]
+        );
+
+        let mut shift = RangeMap::new();
+        shift.insert(Interval::new(0, 60), 1000);
+        let composed = map.then(&shift);
+        for x in [0, 50, 57, 97, 98, 99, 100, 130] {
+            assert_eq!(composed.get(x), shift.get(map.get(x)));
+        }
+    }
+}
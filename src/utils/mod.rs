@@ -1,3 +1,17 @@
+pub mod cycle;
+pub mod geometry;
+pub mod graph;
+pub mod grid;
+pub mod interval;
 pub mod numbers;
+pub mod poly;
+pub mod polygon;
+pub mod rational;
+pub mod search;
 
+pub use geometry::*;
+pub use grid::*;
+pub use interval::*;
 pub use numbers::*;
+pub use polygon::*;
+pub use rational::*;
@@ -1,19 +1,151 @@
+/// The least common multiple, 0 if either number is 0. Panics on overflow,
+/// see `checked_lcm`.
 pub fn lcm(a: usize, b: usize) -> usize {
-    a * b / gcd(a, b)
+    checked_lcm(a, b).expect("ERROR: lcm overflows usize")
 }
 
+/// The least common multiple, `None` if it does not fit in a `usize`.
+pub fn checked_lcm(a: usize, b: usize) -> Option<usize> {
+    if a == 0 || b == 0 {
+        return Some(0);
+    }
+    (a / gcd(a, b)).checked_mul(b)
+}
+
+/// The least common multiple of large numbers, 0 if either number is 0.
+pub fn lcm_u128(a: u128, b: u128) -> u128 {
+    if a == 0 || b == 0 {
+        return 0;
+    }
+    a / gcd_u128(a, b) * b
+}
+
+/// The greatest common divisor, with `gcd(0, n) == n`.
 pub fn gcd(a: usize, b: usize) -> usize {
-    let mut max = a;
-    let mut min = b;
-    if min > max {
-        std::mem::swap(&mut max, &mut min);
-    }
-    loop {
-        let res = max % min;
-        if res == 0 {
-            return min;
-        }
-        max = min;
-        min = res;
+    gcd_u128(a as u128, b as u128) as usize
+}
+
+/// The greatest common divisor of signed numbers, never negative. Wraps for
+/// `gcd_i128(i128::MIN, 0)`, whose divisor 2^127 does not fit.
+pub fn gcd_i128(a: i128, b: i128) -> i128 {
+    gcd_u128(a.unsigned_abs(), b.unsigned_abs()) as i128
+}
+
+fn gcd_u128(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// `(g, x, y)` such that `a * x + b * y == g`, `g` being the greatest common
+/// divisor of `a` and `b`, never negative.
+pub fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+    while r != 0 {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_x, x) = (x, old_x - q * x);
+        (old_y, y) = (y, old_y - q * y);
+    }
+    if old_r < 0 {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// The `x` in `0..m` such that `a * x ≡ 1 (mod m)`, `None` if `a` and `m` are
+/// not coprime.
+pub fn mod_inverse(a: i128, m: i128) -> Option<i128> {
+    let (g, x, _) = extended_gcd(a.rem_euclid(m), m);
+    (g == 1).then(|| x.rem_euclid(m))
+}
+
+/// Solve `x ≡ offset (mod period)` for every `(offset, period)` of
+/// `congruences`, periods being positive but not necessarily coprime. Returns
+/// `(x, lcm of the periods)` with `x` the smallest solution in `0..lcm`, or
+/// `None` if the congruences contradict each other or a period is not
+/// positive.
+pub fn crt(congruences: &[(i128, i128)]) -> Option<(i128, i128)> {
+    if congruences.iter().any(|&(_, period)| period <= 0) {
+        return None;
+    }
+    congruences
+        .iter()
+        .try_fold((0, 1), |(x, m), &(offset, period)| {
+            let (g, p, _) = extended_gcd(m, period);
+            let diff = offset - x;
+            if diff % g != 0 {
+                return None;
+            }
+            let lcm = m / g * period;
+            let k = (diff / g % (period / g)) * p % (period / g);
+            Some(((x + m * k).rem_euclid(lcm), lcm))
+        })
+}
+
+/// The first time at which every cycle fires together, given as `(first,
+/// period)`: fired at `first`, then every `period` after that. Cycles that do
+/// not start at 0 are fine, `None` if they never line up or a period is 0.
+pub fn first_alignment(cycles: &[(usize, usize)]) -> Option<usize> {
+    if cycles.iter().any(|&(_, period)| period == 0) {
+        return None;
+    }
+    let congruences = cycles
+        .iter()
+        .map(|&(first, period)| (first as i128 % period as i128, period as i128))
+        .collect::<Vec<_>>();
+    let (x, lcm) = crt(&congruences)?;
+    // a solution before every cycle started does not count
+    let start = cycles.iter().map(|&(first, _)| first as i128).max()?;
+    let x = if x < start {
+        x + (start - x + lcm - 1) / lcm * lcm
+    } else {
+        x
+    };
+    usize::try_from(x).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gcd_lcm() {
+        assert_eq!(gcd(0, 5), 5);
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd_i128(-12, 18), 6);
+        assert_eq!(gcd_i128(0, -5), 5);
+        assert_eq!(lcm(4, 6), 12);
+        assert_eq!(lcm(0, 6), 0);
+        assert_eq!(checked_lcm(usize::MAX, usize::MAX - 1), None);
+        assert_eq!(lcm_u128(u64::MAX as u128, 2), 2 * u64::MAX as u128);
+    }
+
+    #[test]
+    fn test_inverse() {
+        assert_eq!(extended_gcd(240, 46), (2, -9, 47));
+        assert_eq!(mod_inverse(3, 11), Some(4));
+        assert_eq!(mod_inverse(-3, 11), Some(7));
+        assert_eq!(mod_inverse(4, 10), None);
+    }
+
+    #[test]
+    fn test_crt() {
+        assert_eq!(crt(&[(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        assert_eq!(crt(&[(1, 4), (3, 6)]), Some((9, 12)));
+        assert_eq!(crt(&[(1, 4), (2, 6)]), None);
+        assert_eq!(crt(&[]), Some((0, 1)));
+        assert_eq!(first_alignment(&[(3, 3), (5, 5)]), Some(15));
+        assert_eq!(first_alignment(&[(2, 3), (3, 5)]), Some(8));
+        assert_eq!(first_alignment(&[(10, 3), (3, 5)]), Some(13));
+        assert_eq!(first_alignment(&[(14, 3), (3, 5)]), Some(23));
+        assert_eq!(first_alignment(&[(0, 2), (1, 2)]), None);
+        assert_eq!(crt(&[(1, 4), (0, 0)]), None);
+        assert_eq!(crt(&[(1, -4)]), None);
+        assert_eq!(first_alignment(&[(3, 0), (3, 5)]), None);
     }
 }
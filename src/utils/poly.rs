@@ -0,0 +1,98 @@
+//! Finding and extending the polynomial behind a sequence of numbers, exactly.
+
+use itertools::Itertools;
+
+use super::Rational;
+
+/// The first value of each row of the difference table of `values`, down to
+/// the first row of zeros: the coefficients of Newton's forward formula.
+pub fn differences(values: &[i64]) -> Vec<i64> {
+    let mut row = values.to_vec();
+    let mut leading = Vec::new();
+    while row.iter().any(|&n| n != 0) {
+        leading.push(row[0]);
+        row = row.iter().tuple_windows().map(|(a, b)| b - a).collect();
+    }
+    leading
+}
+
+/// The value at index `n` of the sequence starting with `values` at indices
+/// `0..values.len()`, assuming it follows the polynomial of lowest degree
+/// through them. `n` can be negative, to extend the sequence backwards.
+pub fn extrapolate(values: &[i64], n: i64) -> i64 {
+    // sum of Δ^k * (n choose k), the binomial growing one k at a time, which
+    // always divides exactly
+    let mut binomial = 1i128;
+    let mut sum = 0i128;
+    for (k, delta) in differences(values).into_iter().enumerate() {
+        if k > 0 {
+            binomial = binomial * (i128::from(n) - k as i128 + 1) / k as i128;
+        }
+        sum += i128::from(delta) * binomial;
+    }
+    i64::try_from(sum).expect("ERROR: extrapolated value overflows i64")
+}
+
+/// The coefficients, lowest degree first, of the polynomial of lowest degree
+/// going through every `(x, y)` of `points`, whose `x` must all differ.
+pub fn lagrange(points: &[(i64, i64)]) -> Vec<Rational> {
+    let mut coefficients = vec![Rational::ZERO; points.len()];
+    for (i, &(xi, yi)) in points.iter().enumerate() {
+        // yi times the product of (x - xj) / (xi - xj) for every other point
+        let mut basis = vec![Rational::from(yi)];
+        for (j, &(xj, _)) in points.iter().enumerate() {
+            if i == j {
+                continue;
+            }
+            let scale = Rational::new(1, i128::from(xi) - i128::from(xj));
+            let mut next = vec![Rational::ZERO; basis.len() + 1];
+            for (k, &c) in basis.iter().enumerate() {
+                next[k + 1] = next[k + 1] + c * scale;
+                next[k] = next[k] - c * scale * Rational::from(xj);
+            }
+            basis = next;
+        }
+        for (c, b) in coefficients.iter_mut().zip(basis) {
+            *c = *c + b;
+        }
+    }
+    coefficients
+}
+
+/// The polynomial with `coefficients`, lowest degree first, at `x`.
+pub fn evaluate(coefficients: &[Rational], x: i64) -> Rational {
+    coefficients
+        .iter()
+        .rev()
+        .fold(Rational::ZERO, |acc, &c| acc * Rational::from(x) + c)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extrapolate() {
+        let squares = [0, 1, 4, 9];
+        assert_eq!(differences(&squares), vec![0, 1, 2]);
+        assert_eq!(extrapolate(&squares, 10), 100);
+        assert_eq!(extrapolate(&squares, -3), 9);
+        assert_eq!(extrapolate(&[10, 13, 16, 21, 30, 45], 6), 68);
+        assert_eq!(extrapolate(&[10, 13, 16, 21, 30, 45], -1), 5);
+        assert_eq!(extrapolate(&[7, 7, 7], 1_000), 7);
+        assert_eq!(extrapolate(&[0, 0], 5), 0);
+    }
+
+    #[test]
+    fn test_lagrange() {
+        // x^2 / 2 + x / 2, the triangular numbers
+        let coefficients = lagrange(&[(1, 1), (2, 3), (4, 10)]);
+        assert_eq!(
+            coefficients,
+            vec![Rational::ZERO, Rational::new(1, 2), Rational::new(2, 4)]
+        );
+        assert_eq!(evaluate(&coefficients, 100).to_integer(), Some(5_050));
+        assert_eq!(evaluate(&coefficients, -2), Rational::integer(1));
+        assert_eq!(evaluate(&lagrange(&[(0, 1), (2, 2)]), 1).to_string(), "3/2");
+    }
+}
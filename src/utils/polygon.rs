@@ -0,0 +1,152 @@
+//! Simple polygons with integer vertices: area, lattice points and whether a
+//! point is inside, all exact.
+
+use super::{gcd, Direction, Point};
+
+/// Where a point is relative to a polygon.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Location {
+    Inside,
+    Boundary,
+    Outside,
+}
+
+/// A closed polygon, its last vertex joined back to the first. Vertices may
+/// be collinear, e.g. every tile of a path.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Polygon {
+    vertices: Vec<Point<i64>>,
+}
+
+impl Polygon {
+    pub fn new(vertices: Vec<Point<i64>>) -> Self {
+        Self { vertices }
+    }
+
+    /// The polygon drawn by moving from `start` by each `(direction,
+    /// distance)` in turn, e.g. a dig plan.
+    pub fn from_steps(
+        start: Point<i64>,
+        steps: impl IntoIterator<Item = (Direction, i64)>,
+    ) -> Self {
+        let mut vertices = vec![start];
+        let mut at = start;
+        for (direction, distance) in steps {
+            at += direction.delta() * distance;
+            vertices.push(at);
+        }
+        // back at the start, which is already the first vertex
+        if vertices.len() > 1 && vertices.last() == Some(&start) {
+            vertices.pop();
+        }
+        Self { vertices }
+    }
+
+    pub fn vertices(&self) -> &[Point<i64>] {
+        &self.vertices
+    }
+
+    // Every side, from a vertex to the next one.
+    fn edges(&self) -> impl Iterator<Item = (Point<i64>, Point<i64>)> + '_ {
+        let next = self.vertices.iter().cycle().skip(1);
+        self.vertices.iter().copied().zip(next.copied())
+    }
+
+    /// Twice the area, by the shoelace formula: positive when the vertices go
+    /// clockwise with `y` growing downwards, negative otherwise.
+    pub fn signed_double_area(&self) -> i64 {
+        self.edges().map(|(a, b)| a.x * b.y - b.x * a.y).sum()
+    }
+
+    /// The area, rounded down when it is a half.
+    pub fn area(&self) -> i64 {
+        self.signed_double_area().abs() / 2
+    }
+
+    /// How many lattice points are on the sides.
+    pub fn boundary(&self) -> i64 {
+        self.edges()
+            .map(|(a, b)| {
+                let d = b - a;
+                gcd(d.x.unsigned_abs() as usize, d.y.unsigned_abs() as usize) as i64
+            })
+            .sum()
+    }
+
+    /// How many lattice points are strictly inside, by Pick's theorem.
+    pub fn interior(&self) -> i64 {
+        (self.signed_double_area().abs() - self.boundary() + 2) / 2
+    }
+
+    /// Whether `p` is inside, on a side, or outside, by casting a ray towards
+    /// growing `x`.
+    pub fn locate(&self, p: Point<i64>) -> Location {
+        let mut inside = false;
+        for (a, b) in self.edges() {
+            let (ab, ap) = (b - a, p - a);
+            let on_line = ab.x * ap.y == ab.y * ap.x;
+            let within = a.x.min(b.x) <= p.x
+                && p.x <= a.x.max(b.x)
+                && a.y.min(b.y) <= p.y
+                && p.y <= a.y.max(b.y);
+            if on_line && within {
+                return Location::Boundary;
+            }
+            // sides crossing the ray's line, counting their lower end only
+            if (a.y > p.y) != (b.y > p.y) {
+                // x of the crossing compared to p.x, without dividing
+                let cross = ab.x * ap.y - ab.y * ap.x;
+                if (cross > 0) == (ab.y > 0) {
+                    inside = !inside;
+                }
+            }
+        }
+        if inside {
+            Location::Inside
+        } else {
+            Location::Outside
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_polygon() {
+        use Direction::*;
+        // a 4 by 3 rectangle with a 2 by 1 notch out of its bottom edge
+        let polygon = Polygon::from_steps(
+            Point::new(0, 0),
+            [
+                (East, 4),
+                (South, 3),
+                (West, 1),
+                (North, 1),
+                (West, 2),
+                (South, 1),
+                (West, 1),
+                (North, 3),
+            ],
+        );
+        assert_eq!(polygon.vertices().len(), 8);
+        assert_eq!(polygon.signed_double_area(), 20);
+        assert_eq!(polygon.area(), 10);
+        assert_eq!(polygon.boundary(), 16);
+        assert_eq!(polygon.interior(), 3);
+        assert_eq!(polygon.locate(Point::new(1, 1)), Location::Inside);
+        assert_eq!(polygon.locate(Point::new(2, 2)), Location::Boundary);
+        assert_eq!(polygon.locate(Point::new(2, 3)), Location::Outside);
+        assert_eq!(polygon.locate(Point::new(5, 1)), Location::Outside);
+        assert_eq!(polygon.locate(Point::new(-1, 2)), Location::Outside);
+
+        let triangle = Polygon::new(vec![Point::new(0, 0), Point::new(0, 4), Point::new(4, 0)]);
+        assert_eq!(triangle.signed_double_area(), -16);
+        assert_eq!(triangle.boundary(), 12);
+        assert_eq!(triangle.interior(), 3);
+        assert_eq!(triangle.locate(Point::new(1, 1)), Location::Inside);
+        assert_eq!(triangle.locate(Point::new(2, 2)), Location::Boundary);
+        assert_eq!(triangle.locate(Point::new(3, 3)), Location::Outside);
+    }
+}
@@ -0,0 +1,234 @@
+//! Exact fractions and integer square roots, for the puzzles that would
+//! otherwise reach for `f64`.
+
+use std::{
+    cmp::Ordering,
+    fmt::Display,
+    ops::{Add, Div, Mul, Neg, Sub},
+};
+
+use super::gcd_i128;
+
+/// A fraction of `i128`s, always reduced and with a positive denominator, so
+/// equal fractions compare and hash the same.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rational {
+    num: i128,
+    den: i128,
+}
+
+impl Rational {
+    pub const ZERO: Rational = Rational::integer(0);
+    pub const ONE: Rational = Rational::integer(1);
+
+    /// Panics if `den` is 0.
+    pub fn new(num: i128, den: i128) -> Self {
+        assert!(den != 0, "ERROR: fraction with a zero denominator");
+        let gcd = gcd_i128(num, den) * den.signum();
+        Self {
+            num: num / gcd,
+            den: den / gcd,
+        }
+    }
+
+    pub const fn integer(n: i128) -> Self {
+        Self { num: n, den: 1 }
+    }
+
+    pub fn num(&self) -> i128 {
+        self.num
+    }
+
+    pub fn den(&self) -> i128 {
+        self.den
+    }
+
+    pub fn is_integer(&self) -> bool {
+        self.den == 1
+    }
+
+    /// The fraction as an integer, if it is one.
+    pub fn to_integer(&self) -> Option<i128> {
+        self.is_integer().then_some(self.num)
+    }
+
+    pub fn floor(&self) -> i128 {
+        self.num.div_euclid(self.den)
+    }
+
+    pub fn ceil(&self) -> i128 {
+        -(-*self).floor()
+    }
+
+    pub fn abs(&self) -> Self {
+        Self {
+            num: self.num.abs(),
+            den: self.den,
+        }
+    }
+
+    pub fn signum(&self) -> i128 {
+        self.num.signum()
+    }
+
+    /// `1 / self`, panics if `self` is 0.
+    pub fn recip(&self) -> Self {
+        Self::new(self.den, self.num)
+    }
+}
+
+/// The largest integer whose square is at most `n`, `None` for negative `n`.
+pub fn isqrt(n: i128) -> Option<i128> {
+    (n >= 0).then(|| n.isqrt())
+}
+
+/// The square root of `n` if it is a perfect square.
+pub fn exact_sqrt(n: i128) -> Option<i128> {
+    isqrt(n).filter(|root| root * root == n)
+}
+
+impl From<i64> for Rational {
+    fn from(n: i64) -> Self {
+        Self::integer(n.into())
+    }
+}
+
+impl From<i128> for Rational {
+    fn from(n: i128) -> Self {
+        Self::integer(n)
+    }
+}
+
+impl Ord for Rational {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // denominators are positive, cross multiplying keeps the order
+        match (
+            self.num.checked_mul(other.den),
+            other.num.checked_mul(self.den),
+        ) {
+            (Some(a), Some(b)) => a.cmp(&b),
+            _ => cmp_fractions(self.num, self.den, other.num, other.den),
+        }
+    }
+}
+
+// Compare `a / b` with `c / d`, for positive `b` and `d`, without multiplying:
+// by their integer parts, then by the reciprocals of what is left, as
+// continued fractions. The numbers only shrink, like in Euclid's algorithm.
+fn cmp_fractions(a: i128, b: i128, c: i128, d: i128) -> Ordering {
+    let (q1, r1) = (a.div_euclid(b), a.rem_euclid(b));
+    let (q2, r2) = (c.div_euclid(d), c.rem_euclid(d));
+    match (q1.cmp(&q2), r1, r2) {
+        (Ordering::Equal, 0, 0) => Ordering::Equal,
+        (Ordering::Equal, 0, _) => Ordering::Less,
+        (Ordering::Equal, _, 0) => Ordering::Greater,
+        // both left between 0 and 1, the larger has the smaller reciprocal
+        (Ordering::Equal, _, _) => cmp_fractions(d, r2, b, r1),
+        (order, _, _) => order,
+    }
+}
+
+impl PartialOrd for Rational {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Add for Rational {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        // over the least common denominator, to keep the numbers small
+        let gcd = gcd_i128(self.den, other.den);
+        let (a, b) = (self.den / gcd, other.den / gcd);
+        Self::new(self.num * b + other.num * a, self.den * b)
+    }
+}
+
+impl Sub for Rational {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        self + -other
+    }
+}
+
+impl Mul for Rational {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        // reduce crosswise first, to keep the numbers small
+        let (g1, g2) = (gcd_i128(self.num, other.den), gcd_i128(other.num, self.den));
+        Self::new(
+            (self.num / g1) * (other.num / g2),
+            (self.den / g2) * (other.den / g1),
+        )
+    }
+}
+
+impl Div for Rational {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self {
+        let recip = other.recip();
+        self.mul(recip)
+    }
+}
+
+impl Neg for Rational {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self {
+            num: -self.num,
+            den: self.den,
+        }
+    }
+}
+
+impl Display for Rational {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.den {
+            1 => write!(f, "{}", self.num),
+            den => write!(f, "{}/{den}", self.num),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rational() {
+        let half = Rational::new(2, 4);
+        assert_eq!((half.num(), half.den()), (1, 2));
+        assert_eq!(Rational::new(3, -6), -half);
+        assert_eq!(half + Rational::new(1, 3), Rational::new(5, 6));
+        assert_eq!(half - Rational::ONE, Rational::new(-1, 2));
+        assert_eq!(half * Rational::from(4i64), Rational::integer(2));
+        assert_eq!(half / Rational::new(1, 4), Rational::integer(2));
+        assert!(Rational::new(-1, 3) < Rational::ZERO);
+        assert!(Rational::new(2, 3) > half);
+        // too large to cross multiply
+        let huge = Rational::new(i128::MAX, 3);
+        assert!(huge < Rational::new(i128::MAX, 2));
+        assert!(huge > Rational::new(i128::MAX - 3, 3));
+        assert!(-huge < Rational::new(i128::MIN + 1, 5));
+        assert_eq!(huge.cmp(&huge), Ordering::Equal);
+        assert_eq!(Rational::new(-7, 2).floor(), -4);
+        assert_eq!(Rational::new(-7, 2).ceil(), -3);
+        assert_eq!(Rational::new(7, 2).to_string(), "7/2");
+        assert_eq!(Rational::new(8, 2).to_integer(), Some(4));
+        assert_eq!(Rational::new(0, -5), Rational::ZERO);
+    }
+
+    #[test]
+    fn test_sqrt() {
+        assert_eq!(isqrt(24), Some(4));
+        assert_eq!(isqrt(25), Some(5));
+        assert_eq!(isqrt(-1), None);
+        assert_eq!(exact_sqrt(1 << 100), Some(1 << 50));
+        assert_eq!(exact_sqrt((1 << 100) + 1), None);
+    }
+}
@@ -0,0 +1,297 @@
+//! Shortest paths over any graph given by a successor function: breadth
+//! first, Dijkstra, A* and Dijkstra on a bucket queue for small integer
+//! weights.
+
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+/// A shortest path found by a search.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Search<N, C> {
+    /// From the start it left, to the goal, both included.
+    pub path: Vec<N>,
+    pub cost: C,
+    /// How many nodes were taken off the frontier, the goal included.
+    pub expanded: usize,
+}
+
+// The nodes seen so far, each once, with the best known way to reach them.
+struct Explored<N, C> {
+    nodes: Vec<(N, Option<usize>, C)>,
+    index: HashMap<N, usize>,
+}
+
+impl<N: Clone + Eq + Hash, C: Copy + Ord> Explored<N, C> {
+    fn new() -> Self {
+        Self {
+            nodes: Vec::new(),
+            index: HashMap::new(),
+        }
+    }
+
+    // Reach `node` from `parent` at `cost`: its index if that is the best way
+    // so far, `None` if it was already reached as cheaply.
+    fn reach(&mut self, node: N, parent: Option<usize>, cost: C) -> Option<usize> {
+        match self.index.get(&node) {
+            Some(&i) if self.nodes[i].2 <= cost => None,
+            Some(&i) => {
+                self.nodes[i] = (node, parent, cost);
+                Some(i)
+            }
+            None => {
+                self.index.insert(node.clone(), self.nodes.len());
+                self.nodes.push((node, parent, cost));
+                Some(self.nodes.len() - 1)
+            }
+        }
+    }
+
+    fn cost(&self, i: usize) -> C {
+        self.nodes[i].2
+    }
+
+    fn node(&self, i: usize) -> &N {
+        &self.nodes[i].0
+    }
+
+    fn found(&self, goal: usize, expanded: usize) -> Search<N, C> {
+        let mut path = vec![];
+        let mut at = Some(goal);
+        while let Some(i) = at {
+            path.push(self.nodes[i].0.clone());
+            at = self.nodes[i].1;
+        }
+        path.reverse();
+        Search {
+            path,
+            cost: self.cost(goal),
+            expanded,
+        }
+    }
+}
+
+/// The path with the fewest steps from any of `starts` to a node where
+/// `is_goal` holds.
+pub fn bfs<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut successors: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<Search<N, usize>>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut explored = Explored::new();
+    let mut frontier = starts
+        .into_iter()
+        .filter_map(|start| explored.reach(start, None, 0))
+        .collect::<VecDeque<_>>();
+    let mut expanded = 0;
+    while let Some(i) = frontier.pop_front() {
+        expanded += 1;
+        if is_goal(explored.node(i)) {
+            return Some(explored.found(i, expanded));
+        }
+        let steps = explored.cost(i) + 1;
+        for next in successors(explored.node(i)) {
+            // the first time a node is reached is the closest
+            if !explored.index.contains_key(&next) {
+                frontier.extend(explored.reach(next, Some(i), steps));
+            }
+        }
+    }
+    None
+}
+
+/// The fewest steps from `start` to every node at most `max_steps` away.
+pub fn reachable<N, I>(
+    start: N,
+    mut successors: impl FnMut(&N) -> I,
+    max_steps: usize,
+) -> HashMap<N, usize>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut steps = HashMap::from([(start.clone(), 0)]);
+    let mut frontier = VecDeque::from([(start, 0)]);
+    while let Some((node, n)) = frontier.pop_front() {
+        if n == max_steps {
+            continue;
+        }
+        for next in successors(&node) {
+            if !steps.contains_key(&next) {
+                steps.insert(next.clone(), n + 1);
+                frontier.push_back((next, n + 1));
+            }
+        }
+    }
+    steps
+}
+
+/// The cheapest path from any of `starts` to a node where `is_goal` holds,
+/// `successors` giving each next node with the cost to step there.
+pub fn dijkstra<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    successors: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Option<Search<N, C>>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar(starts, successors, |_| C::default(), is_goal)
+}
+
+/// Dijkstra, exploring first the nodes whose cost so far plus `heuristic`
+/// is lowest. The path is the cheapest as long as `heuristic` never says
+/// more than the cost left to a goal, and never drops by more than the cost
+/// of a step.
+pub fn astar<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut successors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<Search<N, C>>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut explored = Explored::new();
+    let mut frontier = BinaryHeap::new();
+    for start in starts {
+        let estimate = heuristic(&start);
+        if let Some(i) = explored.reach(start, None, C::default()) {
+            frontier.push(Reverse((estimate, C::default(), i)));
+        }
+    }
+    let mut expanded = 0;
+    while let Some(Reverse((_, cost, i))) = frontier.pop() {
+        if cost > explored.cost(i) {
+            // reached more cheaply since
+            continue;
+        }
+        expanded += 1;
+        if is_goal(explored.node(i)) {
+            return Some(explored.found(i, expanded));
+        }
+        for (next, step) in successors(explored.node(i)) {
+            let estimate = heuristic(&next);
+            if let Some(j) = explored.reach(next, Some(i), cost + step) {
+                frontier.push(Reverse((cost + step + estimate, cost + step, j)));
+            }
+        }
+    }
+    None
+}
+
+/// Dijkstra for small integer step costs, keeping the frontier in a bucket
+/// per cost instead of a heap.
+pub fn dial<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut successors: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<Search<N, usize>>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = (N, usize)>,
+{
+    let mut explored = Explored::new();
+    // the nodes at cost `base + k` in bucket `k`
+    let mut buckets = VecDeque::from([Vec::new()]);
+    let mut base = 0;
+    for start in starts {
+        buckets[0].extend(explored.reach(start, None, 0));
+    }
+    let mut expanded = 0;
+    while let Some(mut bucket) = buckets.pop_front() {
+        while let Some(i) = bucket.pop() {
+            if explored.cost(i) < base {
+                continue;
+            }
+            expanded += 1;
+            if is_goal(explored.node(i)) {
+                return Some(explored.found(i, expanded));
+            }
+            for (next, step) in successors(explored.node(i)) {
+                let Some(j) = explored.reach(next, Some(i), base + step) else {
+                    continue;
+                };
+                if step == 0 {
+                    bucket.push(j);
+                    continue;
+                }
+                if buckets.len() < step {
+                    buckets.resize_with(step, Vec::new);
+                }
+                buckets[step - 1].push(j);
+            }
+        }
+        base += 1;
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // From each number, +1 for 1 or *2 for 3.
+    fn successors(&n: &u32) -> Vec<(u32, usize)> {
+        vec![(n + 1, 1), (n * 2, 3)]
+    }
+
+    #[test]
+    fn test_bfs() {
+        let found = bfs([1], |&n| [n + 1, n * 2], |&n| n == 10).unwrap();
+        assert_eq!(found.path, vec![1, 2, 4, 5, 10]);
+        assert_eq!(found.cost, 4);
+        assert!(bfs(
+            [1],
+            |&n| [n * 2].into_iter().filter(|&n| n < 100),
+            |&n| n == 10
+        )
+        .is_none());
+
+        let steps = reachable(0i32, |&n| [n - 1, n + 1], 3);
+        assert_eq!(steps.len(), 7);
+        assert_eq!(steps[&-3], 3);
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        // counting up to 4 is cheaper than doubling 2
+        let found = dijkstra([1], successors, |&n| n == 22).unwrap();
+        assert_eq!(found.path, vec![1, 2, 3, 4, 5, 10, 11, 22]);
+        assert_eq!(found.cost, 11);
+
+        let dial = dial([1], successors, |&n| n == 22).unwrap();
+        assert_eq!((dial.path, dial.cost), (found.path.clone(), found.cost));
+
+        // a distance to 22 that never overestimates
+        let astar = astar(
+            [1],
+            successors,
+            |&n| 22u32.abs_diff(n).min(1) as usize,
+            |&n| n == 22,
+        )
+        .unwrap();
+        assert_eq!(astar.cost, found.cost);
+        assert!(astar.expanded <= found.expanded);
+
+        let from_two = dijkstra([1, 20], successors, |&n| n == 22).unwrap();
+        assert_eq!(from_two.path, vec![20, 21, 22]);
+        assert!(dijkstra(
+            [1],
+            |&n| [(n + 1, 1)].into_iter().filter(|&(n, _)| n < 5),
+            |&n| n == 22
+        )
+        .is_none());
+    }
+}
@@ -0,0 +1,26 @@
+// The examples of the puzzles, one test per `inputs/day-N-test*.txt` and part.
+// The tests are generated by `build.rs` from the `.answers` sidecar files.
+
+use aoc_2023_lib::{input, registry};
+use std::path::Path;
+
+fn check(day: u8, part: u8, file: &str, expected: &str) {
+    let entry = registry()
+        .get(day)
+        .copied()
+        .unwrap_or_else(|| panic!("ERROR: there is no solution for day {day}"));
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("inputs")
+        .join(file);
+    let source = input::Source::Path(path);
+    let raw = input::load(day, &source).unwrap_or_else(|e| panic!("ERROR: {e}"));
+    let parsed = (entry.parse)(&raw).unwrap_or_else(|e| panic!("ERROR: {file}: {e}"));
+    let answer = match part {
+        1 => parsed.part_1(),
+        _ => parsed.part_2(),
+    }
+    .unwrap_or_else(|e| panic!("ERROR: {file} part {part}: {e}"));
+    assert_eq!(answer.to_string(), expected, "{file} part {part}");
+}
+
+include!(concat!(env!("OUT_DIR"), "/examples.rs"));